@@ -47,7 +47,19 @@ pub enum Expression {
     BooleanLiteral(bool),
     // Integer literals
     UnsignedBitVector(UnsignedBitVectorData),
-    SignedBitVector(SignedBitVectorData)
+    SignedBitVector(SignedBitVectorData),
+    // An integer literal as written in a user condition; replaced by a literal of its type once
+    // the type is known (see infer_literal_types)
+    IntegerLiteral(IntegerLiteralData),
+    // A test of which variant of an enum a variable holds; only appears in user conditions, and
+    // is replaced by a test of the enum's discriminant before the VC is generated.
+    VariantTest(VariantTestData),
+    // A sub-expression converted to another integer type, as with Rust's "as"
+    Cast(CastData),
+    // The element of an array at an index
+    Select(SelectData),
+    // An array with the element at an index replaced
+    Store(StoreData)
 }
 
 // Used for representing Expression types as strings, recursively.
@@ -65,7 +77,25 @@ impl fmt::Display for Expression {
             },
             Expression::SignedBitVector(ref s) => {
                 write!(f, "({} : i{})", s.value, s.size.to_string())
-            }
+            },
+            Expression::IntegerLiteral(ref l) => {
+                match l.ty {
+                    Some(ref ty) => write!(f, "({} : {})", l.text, ty),
+                    None => write!(f, "({} : {{integer}})", l.text),
+                }
+            },
+            Expression::VariantTest(ref v) => {
+                if v.bindings.is_empty() {
+                    write!(f, "({} is {})", v.name, v.variant)
+                } else {
+                    write!(f, "({} is {}({}))", v.name, v.variant, v.bindings.join(", "))
+                }
+            },
+            Expression::Cast(ref c) => write!(f, "({} as {})", *c.e, c.ty),
+            Expression::Select(ref s) => write!(f, "({}[{}])", *s.array, *s.index),
+            Expression::Store(ref s) => {
+                write!(f, "({}[{} := {}])", *s.array, *s.index, *s.value)
+            },
         }
     }
 }
@@ -108,6 +138,50 @@ pub struct SignedBitVectorData {
     pub value: i64,
 }
 
+#[derive(Clone, PartialEq)]
+pub struct IntegerLiteralData {
+    // The literal as written, without its suffix (ie: `-1_000`, `0xFF` or `0b1010`)
+    pub text: String,
+    // The type given by its suffix, if any
+    pub ty: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct VariantTestData {
+    // The name of the enum variable being tested
+    pub name: String,
+    // The name of the variant
+    pub variant: String,
+    // Names bound to each field of the variant, if any
+    pub bindings: Vec<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct CastData {
+    // The expression being converted
+    pub e: Box<Expression>,
+    // The integer type it is converted to
+    pub ty: String,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SelectData {
+    // The array being read
+    pub array: Box<Expression>,
+    // The index of the element, as a usize
+    pub index: Box<Expression>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct StoreData {
+    // The array being written
+    pub array: Box<Expression>,
+    // The index of the element, as a usize
+    pub index: Box<Expression>,
+    // The new value of the element
+    pub value: Box<Expression>,
+}
+
 #[derive(Clone, PartialEq)]
 pub enum BinaryOperator {
     // Normal operators
@@ -219,6 +293,21 @@ pub fn substitute_variable_with_expression (source_expression: &mut Expression,
             // Recurisvely call the sub-expression
             substitute_variable_with_expression(&mut(*u.e), target, replacement);
         },
+        &mut Expression::Cast(ref mut c) => {
+            // Recurisvely call the sub-expression
+            substitute_variable_with_expression(&mut(*c.e), target, replacement);
+        },
+        &mut Expression::Select(ref mut s) => {
+            // Recurisvely call the sub-expressions
+            substitute_variable_with_expression(&mut(*s.array), target, replacement);
+            substitute_variable_with_expression(&mut(*s.index), target, replacement);
+        },
+        &mut Expression::Store(ref mut s) => {
+            // Recurisvely call the sub-expressions
+            substitute_variable_with_expression(&mut(*s.array), target, replacement);
+            substitute_variable_with_expression(&mut(*s.index), target, replacement);
+            substitute_variable_with_expression(&mut(*s.value), target, replacement);
+        },
         &mut Expression::VariableMapping(ref mut v) => {
             // Substitute the variable if it matches the target
             if v == target {
@@ -236,6 +325,151 @@ pub fn substitute_variable_with_expression (source_expression: &mut Expression,
     }
 }
 
+/// Recurses through an Expression and renames any variable named `target`, or any field of
+/// `target` (ie: `target.0`), so that its name begins with `replacement` instead.
+///
+/// # Arguments:
+/// * `source_expression` - The Expression to be recursed through.
+/// * `target` - The name of the variable to be renamed.
+/// * `replacement` - The new name of the variable.
+///
+/// # Remarks:
+/// * Unlike `substitute_variable_with_expression`, the type of the variable is ignored.
+///
+pub fn rename_variable (source_expression: &mut Expression, target: &str, replacement: &str) {
+    match source_expression {
+        &mut Expression::BinaryExpression(ref mut b) => {
+            // Recurisvely call the sub-expressions
+            rename_variable(&mut(*b.left), target, replacement);
+            rename_variable(&mut(*b.right), target, replacement);
+        },
+        &mut Expression::UnaryExpression(ref mut u) => {
+            // Recurisvely call the sub-expression
+            rename_variable(&mut(*u.e), target, replacement);
+        },
+        &mut Expression::Cast(ref mut c) => {
+            // Recurisvely call the sub-expression
+            rename_variable(&mut(*c.e), target, replacement);
+        },
+        &mut Expression::Select(ref mut s) => {
+            // Recurisvely call the sub-expressions
+            rename_variable(&mut(*s.array), target, replacement);
+            rename_variable(&mut(*s.index), target, replacement);
+        },
+        &mut Expression::Store(ref mut s) => {
+            // Recurisvely call the sub-expressions
+            rename_variable(&mut(*s.array), target, replacement);
+            rename_variable(&mut(*s.index), target, replacement);
+            rename_variable(&mut(*s.value), target, replacement);
+        },
+        &mut Expression::VariableMapping(ref mut v) => {
+            // Rename the variable, or the field of the variable, if it matches the target
+            if v.name == target {
+                v.name = replacement.to_string();
+            } else if v.name.starts_with(target) && v.name[target.len()..].starts_with('.') {
+                v.name = replacement.to_string() + &v.name[target.len()..];
+            }
+        },
+        _ => {
+            // No renaming should be done
+        }
+    }
+}
+
+/// Returns the name of the value a variable had when the function was called.
+///
+/// # Arguments:
+/// * `name` - The name of the variable, or a path into it (ie: `*p.x`).
+///
+/// # Remarks:
+/// * In a postcondition, `old(x)` is a distinct variable, so it is left alone while the weakest
+///   precondition is generated, and then renamed to `x`, the value on entry.
+///
+pub fn gen_old_name(name: &str) -> String {
+    format!("old({})", name)
+}
+
+/// Returns the name of the variable an `old(..)` name refers to.
+///
+/// # Arguments:
+/// * `name` - The name of a variable.
+///
+/// # Return Value:
+/// * Returns `Some(x)` for `old(x)`, or `None` if `name` does not refer to an entry value.
+///
+pub fn strip_old_name(name: &str) -> Option<&str> {
+    if name.starts_with("old(") && name.ends_with(')') {
+        Some(&name[4..name.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// Recurses through an Expression and renames every variable `x` in it to `old(x)`.
+///
+/// # Arguments:
+/// * `source_expression` - The argument of `old(..)` in a condition.
+///
+/// # Remarks:
+/// * `old(e)` is `e` evaluated on entry, so it is `e` with each variable replaced by its entry
+///   value (ie: `old(*x + a[i])` is `old(*x) + old(a)[old(i)]`). Every path is renamed as a
+///   whole, so `old(p.x)` stays a field of `p`.
+/// * Variables that are already entry values are left alone, so `old(old(x))` is `old(x)`.
+///
+pub fn rename_to_old(source_expression: &mut Expression) {
+    match *source_expression {
+        Expression::BinaryExpression(ref mut b) => {
+            rename_to_old(&mut b.left);
+            rename_to_old(&mut b.right);
+        },
+        Expression::UnaryExpression(ref mut u) => rename_to_old(&mut u.e),
+        Expression::Cast(ref mut c) => rename_to_old(&mut c.e),
+        Expression::Select(ref mut s) => {
+            rename_to_old(&mut s.array);
+            rename_to_old(&mut s.index);
+        },
+        Expression::Store(ref mut s) => {
+            rename_to_old(&mut s.array);
+            rename_to_old(&mut s.index);
+            rename_to_old(&mut s.value);
+        },
+        Expression::VariableMapping(ref mut v) => {
+            if strip_old_name(v.name.as_str()).is_none() {
+                v.name = gen_old_name(v.name.as_str());
+            }
+        },
+        Expression::VariantTest(ref mut v) => {
+            if strip_old_name(v.name.as_str()).is_none() {
+                v.name = gen_old_name(v.name.as_str());
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Checks whether an Expression refers to the value of any variable on entry.
+///
+/// # Arguments:
+/// * `expression` - The Expression to be recursed through.
+///
+/// # Remarks:
+/// * A precondition is evaluated on entry, so it must not use `old(..)`.
+///
+pub fn uses_old(expression: &Expression) -> bool {
+    match *expression {
+        Expression::BinaryExpression(ref b) => uses_old(&b.left) || uses_old(&b.right),
+        Expression::UnaryExpression(ref u) => uses_old(&u.e),
+        Expression::Cast(ref c) => uses_old(&c.e),
+        Expression::Select(ref s) => uses_old(&s.array) || uses_old(&s.index),
+        Expression::Store(ref s) => {
+            uses_old(&s.array) || uses_old(&s.index) || uses_old(&s.value)
+        },
+        Expression::VariableMapping(ref v) => strip_old_name(v.name.as_str()).is_some(),
+        Expression::VariantTest(ref v) => strip_old_name(v.name.as_str()).is_some(),
+        _ => false,
+    }
+}
+
 /// Recurses through an Expression and returns the type it would evaluate to.
 ///
 /// # Arguments:
@@ -287,6 +521,15 @@ pub fn determine_evaluation_type ( expression: &Expression ) -> String {
                 },
                 Expression::VariableMapping(ref v) => v.var_type.clone(),
                 Expression::BooleanLiteral(_) => "bool".to_string(),
+                Expression::VariantTest(_) => "bool".to_string(),
+                // ty_check ensures the literal has a type
+                Expression::IntegerLiteral(ref l) => l.ty.clone().unwrap(),
+                Expression::Cast(ref c) => c.ty.clone(),
+                Expression::Select(ref s) => {
+                    // ty_check ensures the array has an array type
+                    array_element_type(determine_evaluation_type(&*s.array).as_str()).unwrap()
+                },
+                Expression::Store(ref s) => determine_evaluation_type(&*s.array),
                 Expression::UnsignedBitVector(ref u) => {
                     match u.size {
                         8 => "u8".to_string(),
@@ -321,6 +564,139 @@ pub fn determine_evaluation_type ( expression: &Expression ) -> String {
     }
 }
 
+/// Recurses through an Expression and gives each integer literal in it a type, replacing it with a
+/// literal of that type.
+///
+/// # Arguments:
+/// * `expression` - An Expression parsed from a user condition.
+///
+/// # Return:
+/// * Ok(true) if every literal given a type is in its range.
+/// * Err(String) otherwise, the String containing a message about the first literal out of range.
+///
+/// # Remarks:
+/// * A literal with a suffix (ie: `10i32`) has the type of its suffix. Without one, it has the
+///   type of the operand it is combined or compared with (ie: `5` in `x: i32 < 5`), and an index
+///   has the type `usize`.
+/// * A literal whose type cannot be inferred is left alone, and is reported by `ty_check`.
+///
+pub fn infer_literal_types(expression: &mut Expression) -> Result<bool, String> {
+    infer_literal_type(expression, None)
+}
+
+// Gives the integer literals in an expression a type, given the type the expression is expected
+// to have, if known
+fn infer_literal_type(expression: &mut Expression, expected: Option<String>)
+                      -> Result<bool, String> {
+    let literal = match *expression {
+        Expression::BinaryExpression(ref mut b) => {
+            let (left, right) = match b.op {
+                BinaryOperator::Addition
+                | BinaryOperator::Subtraction
+                | BinaryOperator::Multiplication
+                | BinaryOperator::Division
+                | BinaryOperator::Modulo
+                | BinaryOperator::BitwiseOr
+                | BinaryOperator::BitwiseAnd
+                | BinaryOperator::BitwiseXor => {
+                    let ty = known_type(&b.left).or(known_type(&b.right)).or(expected);
+                    (ty.clone(), ty)
+                },
+                // The shift amount may be any integer type; without one, it has the type of
+                // the value shifted
+                BinaryOperator::BitwiseLeftShift | BinaryOperator::BitwiseRightShift => {
+                    let ty = known_type(&b.left).or(expected);
+                    (ty.clone(), known_type(&b.right).or(ty))
+                },
+                BinaryOperator::SignedMultiplicationDoesNotOverflow
+                | BinaryOperator::SignedMultiplicationDoesNotUnderflow
+                | BinaryOperator::UnsignedMultiplicationDoesNotOverflow
+                | BinaryOperator::LessThan
+                | BinaryOperator::LessThanOrEqual
+                | BinaryOperator::GreaterThan
+                | BinaryOperator::GreaterThanOrEqual
+                | BinaryOperator::Equal
+                | BinaryOperator::NotEqual => {
+                    let ty = known_type(&b.left).or(known_type(&b.right));
+                    (ty.clone(), ty)
+                },
+                BinaryOperator::And
+                | BinaryOperator::Or
+                | BinaryOperator::Xor
+                | BinaryOperator::Implication
+                | BinaryOperator::BiImplication => (None, None),
+            };
+            return infer_literal_type(&mut b.left, left)
+                .and_then(|_| infer_literal_type(&mut b.right, right));
+        },
+        Expression::UnaryExpression(ref mut u) => {
+            return match u.op {
+                UnaryOperator::Negation | UnaryOperator::BitwiseNot => {
+                    infer_literal_type(&mut u.e, expected)
+                },
+                UnaryOperator::Not => infer_literal_type(&mut u.e, None),
+            };
+        },
+        Expression::Cast(ref mut c) => return infer_literal_type(&mut c.e, None),
+        Expression::Select(ref mut s) => {
+            return infer_literal_type(&mut s.array, None)
+                .and_then(|_| infer_literal_type(&mut s.index, Some("usize".to_string())));
+        },
+        Expression::Store(ref mut s) => {
+            let element = known_type(&s.array).and_then(|t| array_element_type(t.as_str()));
+            return infer_literal_type(&mut s.array, None)
+                .and_then(|_| infer_literal_type(&mut s.index, Some("usize".to_string())))
+                .and_then(|_| infer_literal_type(&mut s.value, element));
+        },
+        Expression::IntegerLiteral(ref l) => {
+            match l.ty.clone().or(expected) {
+                Some(ty) => try!(gen_integer_literal(l.text.as_str(), ty.as_str())),
+                None => return Ok(true),
+            }
+        },
+        _ => return Ok(true),
+    };
+    *expression = literal;
+    Ok(true)
+}
+
+// Finds the type of an expression, if it does not depend on a literal without a type
+fn known_type(expression: &Expression) -> Option<String> {
+    match *expression {
+        Expression::BinaryExpression(ref b) => {
+            match b.op {
+                BinaryOperator::Addition
+                | BinaryOperator::Subtraction
+                | BinaryOperator::Multiplication
+                | BinaryOperator::Division
+                | BinaryOperator::Modulo
+                | BinaryOperator::BitwiseOr
+                | BinaryOperator::BitwiseAnd
+                | BinaryOperator::BitwiseXor => {
+                    known_type(&b.left).or(known_type(&b.right))
+                },
+                BinaryOperator::BitwiseLeftShift
+                | BinaryOperator::BitwiseRightShift => known_type(&b.left),
+                _ => Some("bool".to_string()),
+            }
+        },
+        Expression::UnaryExpression(ref u) => {
+            match u.op {
+                UnaryOperator::Negation | UnaryOperator::BitwiseNot => known_type(&u.e),
+                _ => Some("bool".to_string()),
+            }
+        },
+        Expression::IntegerLiteral(ref l) => l.ty.clone(),
+        Expression::VariableMapping(ref v) => Some(v.var_type.clone()),
+        Expression::Cast(ref c) => Some(c.ty.clone()),
+        Expression::Select(ref s) => {
+            known_type(&s.array).and_then(|t| array_element_type(t.as_str()))
+        },
+        Expression::Store(ref s) => known_type(&s.array),
+        _ => Some(determine_evaluation_type(expression)),
+    }
+}
+
 /// Recurses through an Expression and checks for validity of types, operands, and integer bounds.
 ///
 /// # Arguments:
@@ -359,6 +735,15 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
                                                 b.op
                                             )
                                         )
+                                    // Ensure neither operand is a char
+                                    } else if (l_type == "char") || (r_type == "char") {
+                                        Err(
+                                            format!(
+                                                "Invalid use of binary operator {} on char \
+                                                value(s)",
+                                                b.op
+                                            )
+                                        )
                                     // Ensure both operand types match
                                     } else if l_type != r_type {
                                         Err(
@@ -395,17 +780,16 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
                                                 b.op
                                             )
                                         )
-                                    //Ensure both operand types are of same signedness
-                                    } else if (l_type.starts_with('i') && !r_type.starts_with('i'))
-                                            || (l_type.starts_with('u') && !r_type.starts_with('u')) {
+                                    } else if (l_type == "char") || (r_type == "char") {
                                         Err(
                                             format!(
-                                                "Binary operand types do not match: {} {} {}",
-                                                l_type,
-                                                b.op,
-                                                r_type
+                                                "Invalid use of binary operator {} on char \
+                                                value(s)",
+                                                b.op
                                             )
                                         )
+                                    // The shift amount may be any integer type, so the
+                                    // operand types are not compared
                                     } else {
                                         Ok(true)
                                     }
@@ -425,8 +809,17 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
                                 Ok(_) => {
                                     let l_type: String = determine_evaluation_type(&*b.left);
                                     let r_type: String = determine_evaluation_type(&*b.right);
+                                    // Ensure neither operand is a char
+                                    if (l_type == "char") || (r_type == "char") {
+                                        Err(
+                                            format!(
+                                                "Invalid use of binary operator {} on char \
+                                                value(s)",
+                                                b.op
+                                            )
+                                        )
                                     // Ensure both operand types match
-                                    if l_type != r_type {
+                                    } else if l_type != r_type {
                                         Err(
                                             format!(
                                                 "Binary operand types do not match: {} {} {}",
@@ -565,8 +958,8 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
                                         *u.e
                                     )
                                 )
-                            // Ensure operand is not an unsigned type
-                            } else if is_valid_unsigned(e_type.as_str()) {
+                            // Ensure operand is not an unsigned type or a char
+                            } else if is_valid_unsigned(e_type.as_str()) || e_type == "char" {
                                 Err(
                                     format!(
                                         "Invalid use of operator {} on unsigned value {}",
@@ -583,7 +976,21 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
                 },
                 UnaryOperator::BitwiseNot => {
                     match ty_check(&*u.e) {
-                        Ok(_) => Ok(true),
+                        Ok(_) => {
+                            let e_type: String = determine_evaluation_type(&*u.e);
+                            // Ensure operand is not a char
+                            if e_type == "char" {
+                                Err(
+                                    format!(
+                                        "Invalid use of operator {} on non-integer value {}",
+                                        u.op,
+                                        *u.e
+                                    )
+                                )
+                            } else {
+                                Ok(true)
+                            }
+                        },
                         Err(e) => Err(e)
                     }
                 },
@@ -607,7 +1014,9 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
         Expression::VariableMapping(ref v) => {
             if is_valid_unsigned(v.var_type.as_str())
                || is_valid_signed(v.var_type.as_str())
-               || v.var_type.as_str() == "bool" {
+               || v.var_type.as_str() == "bool"
+               || v.var_type.as_str() == "char"
+               || is_valid_array(v.var_type.as_str()) {
                 Ok(true)
             } else {
                 Err(format!("Invalid or unsupported variable type: \"{}\"", v.var_type))
@@ -616,6 +1025,105 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
         Expression::BooleanLiteral(_) => {
             Ok(true)
         },
+        Expression::VariantTest(_) => {
+            Ok(true)
+        },
+        Expression::IntegerLiteral(ref l) => {
+            match l.ty {
+                // Ensure the value is in the range of its type
+                Some(ref ty) => gen_integer_literal(l.text.as_str(), ty.as_str()).map(|_| true),
+                None => {
+                    Err(
+                        format!(
+                            "The type of the integer literal {} could not be inferred; give it \
+                            a suffix (ie: {}u32)",
+                            l.text,
+                            l.text
+                        )
+                    )
+                },
+            }
+        },
+        Expression::Cast(ref c) => {
+            match ty_check(&*c.e) {
+                Ok(_) => {
+                    let e_type: String = determine_evaluation_type(&*c.e);
+                    // Ensure the target is an integer type; integers, booleans and chars can be
+                    // cast
+                    if is_valid_unsigned(c.ty.as_str()) || is_valid_signed(c.ty.as_str()) {
+                        if is_valid_unsigned(e_type.as_str()) || is_valid_signed(e_type.as_str())
+                           || e_type == "bool" || e_type == "char" {
+                            Ok(true)
+                        } else {
+                            Err(format!("Invalid cast of {} value {} to {}", e_type, *c.e, c.ty))
+                        }
+                    // Only a u8, or a literal Unicode scalar value, can be cast to a char
+                    } else if c.ty == "char" {
+                        match *c.e {
+                            Expression::UnsignedBitVector(ref u) if u.size == 32 => {
+                                if is_valid_char_value(u.value) {
+                                    Ok(true)
+                                } else {
+                                    Err(format!("Out of range value for char type: {:#x}",
+                                                u.value))
+                                }
+                            },
+                            _ if e_type == "u8" || e_type == "char" => Ok(true),
+                            _ => Err(format!("Invalid cast of {} value {} to char", e_type, *c.e)),
+                        }
+                    } else {
+                        Err(format!("Invalid cast of {} to non-integer type {}", *c.e, c.ty))
+                    }
+                },
+                Err(e) => Err(e)
+            }
+        },
+        Expression::Select(ref s) => {
+            match ty_check(&*s.array) {
+                Ok(_) => {
+                    match ty_check(&*s.index) {
+                        Ok(_) => {
+                            let a_type: String = determine_evaluation_type(&*s.array);
+                            let i_type: String = determine_evaluation_type(&*s.index);
+                            // Ensure an array is indexed by a usize
+                            if !is_valid_array(a_type.as_str()) {
+                                Err(format!("Invalid index into non-array value {}", *s.array))
+                            } else if i_type != "usize" {
+                                Err(format!("Invalid array index of type {}: {}", i_type, *s.index))
+                            } else {
+                                Ok(true)
+                            }
+                        },
+                        Err(e) => Err(e)
+                    }
+                },
+                Err(e) => Err(e)
+            }
+        },
+        Expression::Store(ref s) => {
+            match ty_check(&Expression::Select( SelectData {
+                array: s.array.clone(),
+                index: s.index.clone()
+            } )) {
+                Ok(_) => {
+                    match ty_check(&*s.value) {
+                        Ok(_) => {
+                            let a_type: String = determine_evaluation_type(&*s.array);
+                            let v_type: String = determine_evaluation_type(&*s.value);
+                            // Ensure the value has the type of the elements
+                            if array_element_type(a_type.as_str()) != Some(v_type.clone()) {
+                                Err(format!("Invalid store of {} value into {} array",
+                                            v_type, a_type))
+                            } else {
+                                Ok(true)
+                            }
+                        },
+                        Err(e) => Err(e)
+                    }
+                },
+                Err(e) => Err(e)
+            }
+        },
         Expression::UnsignedBitVector(ref u) => {
             match u.size {
                 8 => {
@@ -700,6 +1208,29 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
     }
 }
 
+/// Returns the width in bits of an integer type, or of a char
+///
+/// # Arguments:
+/// * `var_type` - A string slice
+/// * `pointer_width` - The width of `usize` and `isize`
+///
+/// # Return:
+/// * The number of bits in the type, or `None` if it is not a supported integer type
+///
+/// # Remarks:
+/// * A char is a 32-bit Unicode scalar value.
+///
+pub fn type_size(var_type: &str, pointer_width: u8) -> Option<u8> {
+    match var_type {
+        "i8" | "u8" => Some(8),
+        "i16" | "u16" => Some(16),
+        "i32" | "u32" | "char" => Some(32),
+        "i64" | "u64" => Some(64),
+        "isize" | "usize" => Some(pointer_width),
+        _ => None,
+    }
+}
+
 /// Checks if argument string matches one of the supported unsigned integer types
 ///
 /// # Arguments:
@@ -709,13 +1240,14 @@ pub fn ty_check( expression: &Expression ) -> Result<bool, String> {
 /// * `true` if it matches, `false` otherwise
 ///
 /// # Remarks:
-/// * Current supported types: u8, u16, u32, u64
+/// * Current supported types: u8, u16, u32, u64, usize
 ///
 fn is_valid_unsigned(var_type: &str) -> bool {
     (var_type == "u8")
     || (var_type == "u16")
     || (var_type == "u32")
     || (var_type == "u64")
+    || (var_type == "usize")
 }
 
 /// Checks if argument string matches one of the supported signed integer types
@@ -727,11 +1259,155 @@ fn is_valid_unsigned(var_type: &str) -> bool {
 /// * `true` if it matches, `false` otherwise
 ///
 /// # Remarks:
-/// * Current supported types: i8, i16, i32, i64
+/// * Current supported types: i8, i16, i32, i64, isize
 ///
 fn is_valid_signed(var_type: &str) -> bool {
     (var_type == "i8")
     || (var_type == "i16")
     || (var_type == "i32")
     || (var_type == "i64")
+    || (var_type == "isize")
+}
+
+/// Returns the type of the elements of an array or slice type
+///
+/// # Arguments:
+/// * `var_type` - A string slice, such as `[u32; 4]` or `[u32]`
+///
+/// # Return:
+/// * The element type, or `None` if `var_type` is not an array or slice type
+///
+/// # Remarks:
+///
+pub fn array_element_type(var_type: &str) -> Option<String> {
+    if !var_type.starts_with('[') || !var_type.ends_with(']') {
+        return None;
+    }
+    let inner = &var_type[1..var_type.len() - 1];
+    match array_length(var_type) {
+        Some(_) => inner.rfind("; ").map(|i| inner[..i].to_string()),
+        None => Some(inner.to_string()),
+    }
+}
+
+/// Returns the length of a fixed-size array type
+///
+/// # Arguments:
+/// * `var_type` - A string slice, such as `[u32; 4]`
+///
+/// # Return:
+/// * The length, or `None` if `var_type` is not a fixed-size array type
+///
+/// # Remarks:
+/// * Slices (ie: `[u32]`) have no fixed length.
+///
+pub fn array_length(var_type: &str) -> Option<u64> {
+    if !var_type.starts_with('[') || !var_type.ends_with(']') {
+        return None;
+    }
+    let inner = &var_type[1..var_type.len() - 1];
+    match inner.rfind("; ") {
+        Some(i) => inner[i + 2..].parse::<u64>().ok(),
+        None => None,
+    }
+}
+
+/// Returns the literal of an integer type with a given value.
+///
+/// # Arguments:
+/// * `text` - The literal as written, in decimal, hex (`0x`), octal (`0o`) or binary (`0b`), with
+///            an optional sign and underscores.
+/// * `ty` - The integer type of the literal.
+///
+/// # Return:
+/// * Ok(Expression) holding the value, if it is in the range of `ty`.
+/// * Err(String) otherwise.
+///
+/// # Remarks:
+/// * A `usize` or `isize` literal must fit in 64 bits; it is truncated to the pointer width when
+///   the verification condition is checked.
+///
+pub fn gen_integer_literal(text: &str, ty: &str) -> Result<Expression, String> {
+    let negative = text.starts_with('-');
+    let digits = if negative { &text[1..] } else { text };
+    let (radix, digits) = if digits.starts_with("0x") {
+        (16, &digits[2..])
+    } else if digits.starts_with("0o") {
+        (8, &digits[2..])
+    } else if digits.starts_with("0b") {
+        (2, &digits[2..])
+    } else {
+        (10, digits)
+    };
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+    if digits.is_empty() {
+        return Err(format!("Invalid integer literal: {}", text));
+    }
+    let out_of_range = || Err(format!("Out of range value for {} type: {}", ty, text));
+    // The digits are valid for the radix, so only a value above u64::MAX is rejected
+    let magnitude = match u64::from_str_radix(digits.as_str(), radix) {
+        Ok(magnitude) => magnitude,
+        Err(_) => return out_of_range(),
+    };
+
+    if is_valid_unsigned(ty) {
+        let size = type_size(ty, 64).unwrap();
+        if (negative && magnitude != 0) || (size < 64 && magnitude >> size != 0) {
+            return out_of_range();
+        }
+        let literal = Expression::UnsignedBitVector( UnsignedBitVectorData {
+            size: size,
+            value: magnitude
+        } );
+        if ty == "usize" {
+            Ok(Expression::Cast( CastData { e: Box::new(literal), ty: ty.to_string() } ))
+        } else {
+            Ok(literal)
+        }
+    } else if is_valid_signed(ty) {
+        let size = type_size(ty, 64).unwrap();
+        // -2^(n-1) <= value <= 2^(n-1) - 1
+        let limit = 1u64 << (size - 1);
+        if (negative && magnitude > limit) || (!negative && magnitude >= limit) {
+            return out_of_range();
+        }
+        let value = if negative { (magnitude as i64).wrapping_neg() } else { magnitude as i64 };
+        let literal = Expression::SignedBitVector( SignedBitVectorData {
+            size: size,
+            value: value
+        } );
+        if ty == "isize" {
+            Ok(Expression::Cast( CastData { e: Box::new(literal), ty: ty.to_string() } ))
+        } else {
+            Ok(literal)
+        }
+    } else {
+        Err(format!("Integer literal {} used as a value of type {}", text, ty))
+    }
+}
+
+/// Checks if a value is a Unicode scalar value, ie: a valid char
+///
+/// # Arguments:
+/// * `value` - The value
+///
+/// # Return:
+/// * `true` if it is at most `0x10FFFF` and is not a surrogate (`0xD800` to `0xDFFF`), `false`
+///   otherwise
+///
+/// # Remarks:
+///
+pub fn is_valid_char_value(value: u64) -> bool {
+    value <= 0x10FFFF && (value < 0xD800 || value > 0xDFFF)
+}
+
+// Checks if argument string is an array or slice of a supported type
+fn is_valid_array(var_type: &str) -> bool {
+    match array_element_type(var_type) {
+        Some(e) => {
+            is_valid_unsigned(e.as_str()) || is_valid_signed(e.as_str()) || e == "bool"
+            || e == "char" || is_valid_array(e.as_str())
+        },
+        None => false,
+    }
 }
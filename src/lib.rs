@@ -37,15 +37,19 @@ extern crate rustc;
 extern crate rustc_plugin;
 extern crate rustc_data_structures;
 extern crate rustc_const_math;
+extern crate rustc_const_eval;
 //extern crate syntax;
 extern crate rustc_errors as errors;
 
 // External imports
-use rustc_data_structures::indexed_vec::Idx;
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
 use rustc_plugin::Registry;
-use rustc::mir::repr::{Mir, BasicBlock, BasicBlockData, Arg, Temp, Var, ArgDecl, TempDecl, VarDecl};
-use rustc::mir::transform::{Pass, MirPass, MirSource};
-use rustc::ty::{TyCtxt, FnOutput};
+use rustc::mir::repr::{Mir, BasicBlock, BasicBlockData, Arg, Temp, Var, ArgDecl, TempDecl, VarDecl,
+                       Promoted, Lvalue};
+use rustc::mir::transform::{Pass, MirMapPass, MirPassHook, MirSource};
+use rustc::mir::mir_map::MirMap;
+use rustc::ty::{TyCtxt, Ty, FnOutput};
+use rustc::hir::def_id::DefId;
 use syntax::feature_gate::AttributeType;
 use syntax::parse::token::InternedString;
 use syntax::ast::MetaItemKind;
@@ -53,6 +57,8 @@ use errors::{ColorConfig, Handler};
 use syntax::codemap::CodeMap;
 use std::rc::Rc;
 use std::process;
+use std::collections::HashMap;
+use std::fmt;
 
 // Local imports
 use expression::{Expression, BinaryOperator, BinaryExpressionData};
@@ -74,21 +80,84 @@ mod tests;
 pub fn registrar(reg: &mut Registry) {
     // If debug is an argument, set the debug flag to true
     let mut debug = false;
+    // If unroll is an argument, loops without an invariant are unrolled to the given bound
+    let mut unroll = None;
+    // If inline_depth is an argument, calls to functions without a contract are inlined
+    let mut inline_depth = None;
+    // If overflow is an argument, arithmetic overflow is treated according to the given mode
+    let mut overflow = OverflowMode::Panic;
+    // If pointer_width is an argument, usize and isize have the given width rather than the
+    // target's
+    let mut pointer_width = None;
     for arg in reg.args() {
         if arg.clone().unwrap().node == MetaItemKind::Word(InternedString::new("debug")) {
             debug = true;
         }
+        else if let MetaItemKind::NameValue(ref arg_name, ref literal) = arg.node {
+            if arg_name == "unroll" {
+                unroll = Some(parse_unroll_bound(literal));
+            } else if arg_name == "inline_depth" {
+                inline_depth = Some(parse_inline_depth(literal));
+            } else if arg_name == "overflow" {
+                overflow = parse_overflow_mode(literal);
+            } else if arg_name == "pointer_width" {
+                pointer_width = Some(parse_pointer_width(literal));
+            } else {
+                rp_error!("unrecognized plugin argument");
+            }
+        }
         else {
             rp_error!("unrecognized plugin argument");
         }
     }
 
-    let visitor = MirVisitor { debug: debug };
+    let visitor = MirVisitor {
+        debug: debug,
+        unroll: unroll,
+        inline_depth: inline_depth,
+        overflow: overflow,
+        pointer_width: pointer_width
+    };
 
     reg.register_attribute("condition".to_string(), AttributeType::Whitelisted);
+    reg.register_attribute("invariant".to_string(), AttributeType::Whitelisted);
+    reg.register_attribute("unroll".to_string(), AttributeType::Whitelisted);
+    reg.register_attribute("inline_depth".to_string(), AttributeType::Whitelisted);
+    reg.register_attribute("overflow".to_string(), AttributeType::Whitelisted);
+    reg.register_attribute("pointer_width".to_string(), AttributeType::Whitelisted);
     reg.register_mir_pass(Box::new(visitor));
 }
 
+/// Represents how arithmetic overflow is treated when generating the weakest precondition
+///
+/// # Purpose:
+/// * Chosen with the `overflow` plugin argument or the `overflow` attribute of a function.
+///
+/// # Remarks:
+/// * `Panic` is the behavior of debug builds: overflow must never happen, so every arithmetic
+///   operation that can overflow is an obligation.
+/// * `Wrap` is the behavior of release builds: the result wraps around in two's complement, and
+///   there is no obligation.
+/// * `Ignore` assumes arithmetic never overflows, and there is no obligation.
+/// * Division overflow, division by zero, and shift amounts are checked in every mode.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverflowMode {
+    Panic,
+    Wrap,
+    Ignore,
+}
+
+impl fmt::Display for OverflowMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OverflowMode::Panic => write!(f, "panic"),
+            OverflowMode::Wrap => write!(f, "wrap"),
+            OverflowMode::Ignore => write!(f, "ignore"),
+        }
+    }
+}
+
 /// Represents the data from the MIR pass relevant to the function being analyzed
 ///
 
@@ -96,16 +165,139 @@ pub fn registrar(reg: &mut Registry) {
 /// * Used to pass data from the MIR and the computed weakest_precondition
 
 ///
-pub struct MirData<'tcx> {
-    block_data: Vec<&'tcx BasicBlockData<'tcx>>,
-    arg_data: Vec<&'tcx ArgDecl<'tcx>>,
-    var_data: Vec<&'tcx VarDecl<'tcx>>,
-    temp_data: Vec<&'tcx TempDecl<'tcx>>,
+pub struct MirData<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    // The MIR of every function in the crate, for inlining
+    mir_map: &'a MirMap<'tcx>,
+    // The MIR of the function's promoted constants
+    promoted: &'a IndexVec<Promoted, Mir<'tcx>>,
+    // Prepended to the name of every argument, temp, and variable; empty unless inlined
+    prefix: String,
+    block_data: Vec<&'a BasicBlockData<'tcx>>,
+    arg_data: Vec<&'a ArgDecl<'tcx>>,
+    var_data: Vec<&'a VarDecl<'tcx>>,
+    temp_data: Vec<&'a TempDecl<'tcx>>,
     func_return_type: String,
+    return_ty: Option<Ty<'tcx>>,
+    // The place borrowed by each reference temp and variable, keyed by the reference's name
+    borrows: HashMap<String, Lvalue<'tcx>>,
+    // Indices of the blocks targeted by back-edges
+    loop_headers: Vec<usize>,
+    // Loop invariants, keyed by the index of their loop header
+    invariants: HashMap<usize, Expression>,
+    // Loop headers whose body is currently being generated
+    active_loops: Vec<usize>,
+    // Number of iterations to unroll loops without an invariant to
+    unroll: Option<usize>,
+    // Labels of the checks that can be reported on individually
+    checks: Vec<String>,
+    // Number of calls generated so far, used to give each returned value a fresh name
+    call_count: usize,
+    // Maximum number of nested calls to inline
+    inline_depth: Option<usize>,
+    // The functions currently being inlined, outermost first
+    inline_stack: Vec<DefId>,
+    // How arithmetic overflow is treated
+    overflow: OverflowMode,
+    // The width in bits of usize and isize
+    pointer_width: u8,
+}
+
+impl<'a, 'tcx> MirData<'a, 'tcx> {
+    /// Collects the data of a function's MIR.
+    ///
+    /// # Arguments:
+    /// * `tcx` - The type context.
+    /// * `mir` - The MIR of the function.
+    /// * `mir_map` - The MIR of every function in the crate.
+    /// * `prefix` - Prepended to the name of every argument, temp, and variable of the function.
+    ///
+    /// # Remarks:
+    /// * Loop headers, invariants, and the unroll, inline, and overflow settings are left for the
+    ///   caller. The pointer width is the compilation target's.
+    ///
+    fn new(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+           mir: &'a Mir<'tcx>,
+           mir_map: &'a MirMap<'tcx>,
+           prefix: String)
+           -> MirData<'a, 'tcx> {
+        let mut data = MirData {
+            tcx: tcx,
+            mir_map: mir_map,
+            promoted: &mir.promoted,
+            prefix: prefix,
+            block_data: Vec::new(),
+            arg_data: Vec::new(),
+            var_data: Vec::new(),
+            temp_data: Vec::new(),
+            func_return_type: "".to_string(),
+            return_ty: None,
+            borrows: HashMap::new(),
+            loop_headers: Vec::new(),
+            invariants: HashMap::new(),
+            active_loops: Vec::new(),
+            unroll: None,
+            checks: Vec::new(),
+            call_count: 0,
+            inline_depth: None,
+            inline_stack: Vec::new(),
+            overflow: OverflowMode::Panic,
+            pointer_width: match tcx.sess.target.target.target_pointer_width.parse::<u8>() {
+                Ok(width) => width,
+                Err(_) => rp_error!("Unsupported target pointer width: {}",
+                                    tcx.sess.target.target.target_pointer_width),
+            },
+        };
+
+        // Get the basic block data
+        for index in 0..mir.basic_blocks().len() {
+            let block = BasicBlock::new(index);
+            data.block_data.push(&mir[block]);
+        }
+
+        // Get the function argument declarations
+        for index in 0..mir.arg_decls.len() {
+            let arg = Arg::new(index);
+            data.arg_data.push(&mir.arg_decls[arg]);
+        }
+
+        // Get the temp declarations
+        for index in 0..mir.temp_decls.len() {
+            let temp = Temp::new(index);
+            data.temp_data.push(&mir.temp_decls[temp]);
+        }
+
+        // Get the variable declarations
+        for index in 0..mir.var_decls.len() {
+            let var = Var::new(index);
+            data.var_data.push(&mir.var_decls[var]);
+        }
+
+        // Get the return type
+        data.func_return_type = match mir.return_ty {
+            FnOutput::FnConverging(t) => {
+                data.return_ty = Some(t);
+                t.to_string()
+            },
+            _ => { unimplemented!(); }
+        };
+
+        // Find the places the references borrow
+        let borrows = find_borrows(&mut data);
+        data.borrows = borrows;
+
+        data
+    }
 }
 
 // required struct for Pass impl
-struct MirVisitor { debug: bool }
+struct MirVisitor {
+    debug: bool,
+    unroll: Option<usize>,
+    inline_depth: Option<usize>,
+    overflow: OverflowMode,
+    pointer_width: Option<u8>
+}
 
 /// This must exist and must be blank
 impl <'tcx> Pass for MirVisitor {}
@@ -113,15 +305,44 @@ impl <'tcx> Pass for MirVisitor {}
 /// Sets up the compiler to go through MIR code.
 ///
 /// # Remarks:
-impl <'tcx> MirPass<'tcx> for MirVisitor {
+/// * The whole `MirMap` is visited at once, so that callees can be inlined into their callers.
+///
+impl <'tcx> MirMapPass<'tcx> for MirVisitor {
     // Visit the MIR of the entire program
-    fn run_pass<'a>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>, src: MirSource, mir: &mut Mir<'tcx>) {
+    fn run_pass<'a>(&mut self,
+                    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    map: &mut MirMap<'tcx>,
+                    _: &mut [Box<for<'s> MirPassHook<'s>>]) {
+        let map: &MirMap<'tcx> = map;
+        // Report on the functions in the order they are defined
+        let mut def_ids = map.map.keys();
+        def_ids.sort_by_key(|def_id| tcx.map.as_local_node_id(*def_id));
+        for def_id in def_ids {
+            let mir = map.map.get(&def_id).unwrap();
+            let src = MirSource::from_node(tcx, tcx.map.as_local_node_id(def_id).unwrap());
+            self.verify(tcx, src, mir, map);
+        }
+    }
+}
+
+impl MirVisitor {
+    // Verify a single function, if it has a condition attribute
+    fn verify<'a, 'tcx>(&mut self,
+                        tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                        src: MirSource,
+                        mir: &'a Mir<'tcx>,
+                        mir_map: &'a MirMap<'tcx>) {
         let debug = self.debug;
         // Clear the stored attributes in the builder
         let mut pre_string = "".to_string();
         let mut post_string = "".to_string();
-        let pre_expr;
-        let post_expr;
+        let mut invariant_strings = Vec::new();
+        let mut unroll = self.unroll;
+        let mut inline_depth = self.inline_depth;
+        let mut overflow = self.overflow;
+        let mut pointer_width = self.pointer_width;
+        let mut pre_expr;
+        let mut post_expr;
 
         // Store relevant data
         let item_id = src.item_id();
@@ -132,6 +353,11 @@ impl <'tcx> MirPass<'tcx> for MirVisitor {
         // TODO: Find a better way to do this
         for attr in attrs {
             parse_attribute(&mut pre_string, &mut post_string, attr);
+            parse_invariant_attribute(&mut invariant_strings, attr);
+            parse_unroll_attribute(&mut unroll, attr);
+            parse_inline_depth_attribute(&mut inline_depth, attr);
+            parse_overflow_attribute(&mut overflow, attr);
+            parse_pointer_width_attribute(&mut pointer_width, attr);
         }
 
         // TODO: Find a better condition check
@@ -139,48 +365,40 @@ impl <'tcx> MirPass<'tcx> for MirVisitor {
             // Parse the pre- and postcondition arguments
             pre_expr = Some(parser::parse_condition(pre_string.as_str()));
             post_expr = Some(parser::parse_condition(post_string.as_str()));
-
-            // Struct to carry MIR data to later stages
-            let mut data = MirData {
-                block_data: Vec::new(),
-                arg_data: Vec::new(),
-                var_data: Vec::new(),
-                temp_data: Vec::new(),
-                func_return_type: "".to_string(),
-            };
-
-            // Get the basic block data
-            for index in 0..mir.basic_blocks().len() {
-                let block = BasicBlock::new(index);
-                data.block_data.push(&mir[block]);
+            // The precondition is evaluated on entry, so it has no earlier values to refer to
+            if expression::uses_old(pre_expr.as_ref().unwrap()) {
+                rp_error!("The precondition of fn {}(..) uses old(..), which is only allowed in \
+                           a postcondition: {}", name, pre_expr.as_ref().unwrap());
             }
 
-            // Get the function argument declarations
-            for index in 0..mir.arg_decls.len() {
-                let arg = Arg::new(index);
-                data.arg_data.push(&mir.arg_decls[arg]);
+            // Struct to carry MIR data to later stages
+            let mut data = MirData::new(tcx, mir, mir_map, "".to_string());
+            data.unroll = unroll;
+            data.inline_depth = inline_depth;
+            data.overflow = overflow;
+            if let Some(width) = pointer_width {
+                data.pointer_width = width;
             }
 
-            // Get the temp declarations
-            for index in 0..mir.temp_decls.len() {
-                let temp = Temp::new(index);
-                data.temp_data.push(&mir.temp_decls[temp]);
+            // Resolve the conditions against the function's signature
+            resolve_condition(pre_expr.as_mut().unwrap(), &data);
+            resolve_condition(post_expr.as_mut().unwrap(), &data);
+
+            // Attach the loop invariants to the loop headers, both in source order. Without any
+            // invariants, the loops are unrolled instead.
+            data.loop_headers = find_loop_headers(&data);
+            if data.loop_headers.len() != invariant_strings.len()
+               && !(invariant_strings.is_empty() && data.unroll.is_some()) {
+                rp_error!("fn {}(..) has {} loop(s) but {} loop invariant(s) were supplied.",
+                          name, data.loop_headers.len(), invariant_strings.len());
             }
-
-            // Get the variable declarations
-            for index in 0..mir.var_decls.len() {
-                let var = Var::new(index);
-                data.var_data.push(&mir.var_decls[var]);
+            for (header, invariant_string) in data.loop_headers.clone().iter()
+                                                  .zip(invariant_strings.iter()) {
+                let invariant = gen_invariant(parser::parse_condition(invariant_string.as_str()),
+                                              &data);
+                data.invariants.insert(*header, invariant);
             }
 
-            // Get the return type
-            data.func_return_type = match mir.return_ty {
-                FnOutput::FnConverging(t) => {
-                    t.to_string()
-                },
-                _ => { unimplemented!(); }
-            };
-
             if debug {
                 println!("Printing basic blocks...");
                 for index in 0..data.block_data.len() {
@@ -192,11 +410,19 @@ impl <'tcx> MirPass<'tcx> for MirVisitor {
             let weakest_precondition = gen(0, &mut data, &post_expr, debug);
 
             // Create the verification condition, P -> WP
-            let verification_condition: Expression = Expression::BinaryExpression( BinaryExpressionData{
+            let mut verification_condition: Expression = Expression::BinaryExpression( BinaryExpressionData{
                 op: BinaryOperator::Implication,
                 left: Box::new(pre_expr.as_ref().unwrap().clone()),
                 right: Box::new(weakest_precondition.as_ref().unwrap().clone())
             } );
+            // old(x) is the value of x on entry, and immutable statics have their initial values
+            gen_entry_values(&mut verification_condition);
+            let verification_condition = gen_static_values(&verification_condition, &data);
+            // Every char is a Unicode scalar value
+            let verification_condition = gen_char_invariants(&verification_condition);
+            // Every enum argument and static holds one of its variants
+            let verification_condition = gen_discriminant_invariants(&verification_condition,
+                                                                     &data);
 
             // FIXME: Debug should not be a const; it must be user-facing
             if debug {
@@ -208,8 +434,19 @@ impl <'tcx> MirPass<'tcx> for MirVisitor {
                 Err(e) => rp_error!("{}", e),
             }
 
-            // Output to SMT-LIB format
-            gen_smtlib(&verification_condition, name, debug);
+            // Output to SMT-LIB format, with every check enabled
+            let valid = gen_smtlib(&select_checks(&verification_condition, &data, None), name,
+                                   data.overflow, data.pointer_width, debug);
+
+            // Report on each check individually
+            for (index, label) in data.checks.iter().enumerate() {
+                let holds = valid || check_validity(
+                    &select_checks(&verification_condition, &data, Some(index)),
+                    data.pointer_width,
+                    debug
+                );
+                gen_check_report(label, holds);
+            }
         }
     }
 }
@@ -14,9 +14,10 @@ extern crate syntax;
 
 mod expression_parser;
 
-use syntax::ast::{MetaItemKind, Attribute_};
+use syntax::ast::{MetaItemKind, Attribute_, Lit, LitKind};
 use syntax::codemap::{Spanned, CodeMap};
-use expression::{Expression, ty_check};
+use expression::Expression;
+use super::OverflowMode;
 use std::process;
 use std::rc::Rc;
 use errors::{ColorConfig, Handler};
@@ -78,6 +79,209 @@ pub fn parse_attribute(pre_string: &mut String,
     }
 }
 
+/// Analyzes an attribute on a function in the compiled code, and if the attribute is "invariant",
+/// ensures correct usage. If usage is correct, it stores the loop invariant string.
+///
+/// # Arguments:
+/// * `invariant_strings` - The loop invariants found so far, in the order they were supplied.
+/// * `attr` - The attribute being analyzed.
+///
+/// # Remarks:
+/// * Loop invariants are supplied as `#[invariant="I"]`, one attribute per loop in the function.
+///
+pub fn parse_invariant_attribute(invariant_strings: &mut Vec<String>,
+                                 attr: &Spanned<Attribute_>) {
+    if let MetaItemKind::NameValue(ref attribute_name, ref literal) = attr.node.value.node {
+        // Ignore if not an invariant attribute
+        if attribute_name == "invariant" {
+            // Get the argument
+            if let syntax::ast::LitKind::Str(ref i_string, _) = literal.node {
+                invariant_strings.push(i_string.to_string());
+            } else {
+                rp_error!("Loop invariants must be strings. \
+                          Try wrapping invariants in quotation marks.");
+            }
+        }
+    } else if let MetaItemKind::Word(ref attribute_name) = attr.node.value.node {
+        if attribute_name == "invariant" {
+            rp_error!("Invariant attribute must be supplied as #[invariant=\"...\"].");
+        }
+    }
+}
+
+/// Analyzes an attribute on a function in the compiled code, and if the attribute is "unroll",
+/// stores the number of iterations loops in the function are unrolled to.
+///
+/// # Arguments:
+/// * `unroll` - Will contain the user-submitted unroll bound if found.
+/// * `attr` - The attribute being analyzed.
+///
+/// # Remarks:
+/// * The bound is supplied as `#[unroll="8"]` or `#[unroll=8]`.
+///
+pub fn parse_unroll_attribute(unroll: &mut Option<usize>, attr: &Spanned<Attribute_>) {
+    if let MetaItemKind::NameValue(ref attribute_name, ref literal) = attr.node.value.node {
+        // Ignore if not an unroll attribute
+        if attribute_name == "unroll" {
+            *unroll = Some(parse_unroll_bound(literal));
+        }
+    }
+}
+
+/// Analyzes an attribute on a function in the compiled code, and if the attribute is
+/// "inline_depth", stores the maximum number of nested calls to inline into the function.
+///
+/// # Arguments:
+/// * `inline_depth` - Will contain the user-submitted inline depth if found.
+/// * `attr` - The attribute being analyzed.
+///
+/// # Remarks:
+/// * The depth is supplied as `#[inline_depth="2"]` or `#[inline_depth=2]`.
+///
+pub fn parse_inline_depth_attribute(inline_depth: &mut Option<usize>,
+                                    attr: &Spanned<Attribute_>) {
+    if let MetaItemKind::NameValue(ref attribute_name, ref literal) = attr.node.value.node {
+        // Ignore if not an inline_depth attribute
+        if attribute_name == "inline_depth" {
+            *inline_depth = Some(parse_inline_depth(literal));
+        }
+    }
+}
+
+/// Analyzes an attribute on a function in the compiled code, and if the attribute is "overflow",
+/// stores how arithmetic overflow in the function is treated.
+///
+/// # Arguments:
+/// * `overflow` - Will contain the user-submitted overflow mode if found.
+/// * `attr` - The attribute being analyzed.
+///
+/// # Remarks:
+/// * The mode is supplied as `#[overflow="wrap"]`.
+///
+pub fn parse_overflow_attribute(overflow: &mut OverflowMode, attr: &Spanned<Attribute_>) {
+    if let MetaItemKind::NameValue(ref attribute_name, ref literal) = attr.node.value.node {
+        // Ignore if not an overflow attribute
+        if attribute_name == "overflow" {
+            *overflow = parse_overflow_mode(literal);
+        }
+    }
+}
+
+/// Analyzes an attribute on a function in the compiled code, and if the attribute is
+/// "pointer_width", stores the width of `usize` and `isize` in the function.
+///
+/// # Arguments:
+/// * `pointer_width` - Will contain the user-submitted pointer width if found.
+/// * `attr` - The attribute being analyzed.
+///
+/// # Remarks:
+/// * The width is supplied as `#[pointer_width="32"]`.
+///
+pub fn parse_pointer_width_attribute(pointer_width: &mut Option<u8>,
+                                     attr: &Spanned<Attribute_>) {
+    if let MetaItemKind::NameValue(ref attribute_name, ref literal) = attr.node.value.node {
+        // Ignore if not a pointer_width attribute
+        if attribute_name == "pointer_width" {
+            *pointer_width = Some(parse_pointer_width(literal));
+        }
+    }
+}
+
+/// Reads the number of iterations to unroll loops to, from either the `unroll` plugin argument or
+/// the `unroll` attribute.
+///
+/// # Arguments:
+/// * `literal` - The literal supplied as the bound.
+///
+/// # Return:
+/// * The bound, if it is a positive integer.
+///
+/// # Remarks:
+///
+pub fn parse_unroll_bound(literal: &Lit) -> usize {
+    parse_positive_integer(literal, "unroll bound")
+}
+
+/// Reads the maximum number of nested calls to inline, from either the `inline_depth` plugin
+/// argument or the `inline_depth` attribute.
+///
+/// # Arguments:
+/// * `literal` - The literal supplied as the depth.
+///
+/// # Return:
+/// * The depth, if it is a positive integer.
+///
+/// # Remarks:
+///
+pub fn parse_inline_depth(literal: &Lit) -> usize {
+    parse_positive_integer(literal, "inline depth")
+}
+
+/// Reads how arithmetic overflow is treated, from either the `overflow` plugin argument or the
+/// `overflow` attribute.
+///
+/// # Arguments:
+/// * `literal` - The literal supplied as the mode.
+///
+/// # Return:
+/// * The mode, if it is one of "panic", "wrap", or "ignore".
+///
+/// # Remarks:
+///
+pub fn parse_overflow_mode(literal: &Lit) -> OverflowMode {
+    match literal.node {
+        LitKind::Str(ref mode, _) => {
+            match &**mode {
+                "panic" => OverflowMode::Panic,
+                "wrap" => OverflowMode::Wrap,
+                "ignore" => OverflowMode::Ignore,
+                _ => rp_error!("The overflow mode must be \"panic\", \"wrap\", or \"ignore\". \
+                               {} was provided.", mode),
+            }
+        },
+        _ => rp_error!("The overflow mode must be a string."),
+    }
+}
+
+/// Reads the width of `usize` and `isize`, from either the `pointer_width` plugin argument or the
+/// `pointer_width` attribute.
+///
+/// # Arguments:
+/// * `literal` - The literal supplied as the width.
+///
+/// # Return:
+/// * The width in bits, if it is 16, 32, or 64.
+///
+/// # Remarks:
+///
+pub fn parse_pointer_width(literal: &Lit) -> u8 {
+    match parse_positive_integer(literal, "pointer width") {
+        16 => 16,
+        32 => 32,
+        64 => 64,
+        width => rp_error!("The pointer width must be 16, 32, or 64. {} was provided.", width),
+    }
+}
+
+// Reads a positive integer supplied as either an integer or a string literal
+fn parse_positive_integer(literal: &Lit, what: &str) -> usize {
+    let value = match literal.node {
+        LitKind::Int(i, _) => i as usize,
+        LitKind::Str(ref i_string, _) => {
+            match i_string.parse::<usize>() {
+                Ok(i) => i,
+                Err(_) => rp_error!("The {} must be an integer. {} was provided.",
+                                    what, i_string),
+            }
+        },
+        _ => rp_error!("The {} must be an integer.", what),
+    };
+    if value == 0 {
+        rp_error!("The {} must be at least 1.", what);
+    }
+    value
+}
+
 /// Calls the expression parser on a given precondition or postcondition.
 ///
 /// # Arguments:
@@ -88,14 +292,11 @@ pub fn parse_attribute(pre_string: &mut String,
 ///
 /// # Remarks:
 /// * Current supported types: `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `bool`
+/// * Variables written without a type are given an empty type, so the condition is only type
+///   checked once they are resolved against the function's signature (see `resolve_condition`).
 pub fn parse_condition(condition: &str) -> Expression {
     match expression_parser::parse_E1(condition) {
-        Ok(e) => {
-            match ty_check(&e) {
-                Ok(_) => return e,
-                Err(s) => rp_error!("{}", s),
-            }
-        },
+        Ok(e) => e,
         Err(e) => rp_error!("Error parsing condition \"{}\": {:?}", condition, e)
     }
 }
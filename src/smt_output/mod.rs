@@ -16,7 +16,7 @@ use std::process;
 use libsmt::backends::smtlib2::*;
 use libsmt::backends::backend::*;
 use libsmt::backends::z3;
-use libsmt::theories::{bitvec, core};
+use libsmt::theories::{array_ex, bitvec, core};
 use libsmt::logics::qf_abv::*;
 use petgraph::graph::NodeIndex;
 
@@ -25,64 +25,124 @@ use syntax::codemap::CodeMap;
 use std::rc::Rc;
 
 use expression::*;
+use super::OverflowMode;
 
 /// Invokes Z3 to check the satisfiability of a verification condition.
 ///
 /// # Arguments:
 /// * `vc` - A verification condition as an Expression.
 /// * `name` - The name of the function whose verification condition is being checked.
+/// * `overflow` - How arithmetic overflow was treated, which is stated with the verdict.
+/// * `pointer_width` - The width of `usize` and `isize`.
 /// * `debug` - A flag to enable/disable debug printing.
 ///
+/// # Return Value:
+/// * Returns `true` if the verification condition is valid, `false` otherwise.
+///
 /// # Remarks:
 /// * Simply satisfying P->WP isn't enough; that will only tell us if P->WP is _sometimes true_. We
 /// * need to verify that !(P->WP) is *unsatisfiable* to determine that P->WP is _always true_.
 ///
-pub fn gen_smtlib (vc: &Expression, name: String, debug: bool) {
-    // Define an instance of Z3
-    let mut z3: z3::Z3 = Default::default();
-
-    // Declare a logic to use
-    let mut solver = SMTLib2::new(Some(QF_ABV));
-
-    // Check the satisfiability of the solver
-    let vcon = solver.expr2smtlib(vc);
-    let _ = solver.assert(core::OpCodes::Not, &[vcon]);
-
-    let (_, check) = solver.solve(&mut z3, debug);
-    match check {
+pub fn gen_smtlib (vc: &Expression,
+                   name: String,
+                   overflow: OverflowMode,
+                   pointer_width: u8,
+                   debug: bool)
+                   -> bool {
+    match solve_vc(vc, pointer_width, debug) {
         SMTRes::Sat(_, ref model) => {
             println!(
-                "\nfn {}(..)\tVerification Condition is not valid.\n\n{}\n",
+                "\nfn {}(..)\tVerification Condition is not valid.\n\toverflow mode:\t{}\n\n{}\n",
                 name,
+                overflow,
                 model.clone().unwrap()
             );
+            false
         },
         SMTRes::Unsat(..) => {
-            println!("\nfn {}(..)\tVerification Condition is valid.\n", name);
+            println!("\nfn {}(..)\tVerification Condition is valid.\n\toverflow mode:\t{}\n",
+                     name, overflow);
+            true
         },
         SMTRes::Error(ref error, _) => {
-            println!("\nfn {}(..)\tError in Verification Condition Generation.\n{}\n", name, error);
+            println!(
+                "\nfn {}(..)\tError in Verification Condition Generation.\n\toverflow mode:\t{}\n{}\n",
+                name,
+                overflow,
+                error
+            );
+            false
         }
     }
+}
+
+/// Invokes Z3 to check the validity of a verification condition, without printing the result.
+///
+/// # Arguments:
+/// * `vc` - A verification condition as an Expression.
+/// * `pointer_width` - The width of `usize` and `isize`.
+/// * `debug` - A flag to enable/disable debug printing.
+///
+/// # Return Value:
+/// * Returns `true` if the verification condition is valid, `false` otherwise.
+///
+/// # Remarks:
+///
+pub fn check_validity (vc: &Expression, pointer_width: u8, debug: bool) -> bool {
+    match solve_vc(vc, pointer_width, debug) {
+        SMTRes::Unsat(..) => true,
+        _ => false,
+    }
+}
+
+/// Prints whether a labeled check of a verification condition was proven.
+///
+/// # Arguments:
+/// * `label` - The description of the check.
+/// * `holds` - Whether the check was proven to hold.
+///
+/// # Remarks:
+///
+pub fn gen_check_report (label: &str, holds: bool) {
+    if holds {
+        println!("\tproven:\t\t{}\n", label);
+    } else {
+        println!("\tmay fail:\t{}\n", label);
+    }
+}
 
+// Checks the satisfiability of !vc
+fn solve_vc (vc: &Expression, pointer_width: u8, debug: bool) -> SMTRes {
+    // Define an instance of Z3
+    let mut z3: z3::Z3 = Default::default();
+
+    // Declare a logic to use
+    let mut solver = SMTLib2::new(Some(QF_ABV));
+
+    // Check the satisfiability of the solver
+    let vcon = solver.expr2smtlib(vc, pointer_width);
+    let _ = solver.assert(core::OpCodes::Not, &[vcon]);
+
+    let (_, check) = solver.solve(&mut z3, debug);
+    check
 }
 
 pub trait Pred2SMT {
     type Idx: Debug + Clone;
     type Logic: Logic;
 
-    fn expr2smtlib (&mut self, &Expression) -> Self::Idx;
+    fn expr2smtlib (&mut self, &Expression, u8) -> Self::Idx;
 }
 
 impl Pred2SMT for SMTLib2<QF_ABV> {
     type Idx = NodeIndex;
     type Logic = QF_ABV;
 
-    fn expr2smtlib (&mut self, vc: &Expression) -> Self::Idx {
+    fn expr2smtlib (&mut self, vc: &Expression, pointer_width: u8) -> Self::Idx {
         match *vc {
             Expression::BinaryExpression (ref b) => {
-                let l = self.expr2smtlib(b.left.as_ref());
-                let r = self.expr2smtlib(b.right.as_ref());
+                let l = self.expr2smtlib(b.left.as_ref(), pointer_width);
+                let r = self.expr2smtlib(b.right.as_ref(), pointer_width);
                 match b.op {
                     BinaryOperator::Addition => {
                         return self.assert(bitvec::OpCodes::BvAdd, &[l,r]);
@@ -140,9 +200,11 @@ impl Pred2SMT for SMTLib2<QF_ABV> {
                         }
                     },
                     BinaryOperator::BitwiseLeftShift => {
+                        let r = gen_shift_amount(self, b, r, pointer_width);
                         return self.assert(bitvec::OpCodes::BvShl, &[l,r]);
                     },
                     BinaryOperator::BitwiseRightShift => {
+                        let r = gen_shift_amount(self, b, r, pointer_width);
                         // Check for signedness
                         if determine_evaluation_type(vc).starts_with('i') {
                             return self.assert(bitvec::OpCodes::BvAShr, &[l,r]);
@@ -204,7 +266,7 @@ impl Pred2SMT for SMTLib2<QF_ABV> {
                 }
             },
             Expression::UnaryExpression (ref u) => {
-                let n = self.expr2smtlib(u.e.as_ref());
+                let n = self.expr2smtlib(u.e.as_ref(), pointer_width);
                 match u.op {
                     UnaryOperator::Negation => {
                         return self.assert(bitvec::OpCodes::BvNeg, &[n]);
@@ -218,13 +280,9 @@ impl Pred2SMT for SMTLib2<QF_ABV> {
                 }
             },
             Expression::VariableMapping (ref v) => {
-                let sort = match v.var_type.as_ref() {
-                    "bool" => bitvec::Sorts::Bool,
-                    "i8" | "u8" => bitvec::Sorts::BitVector(8),
-                    "i16" | "u16" => bitvec::Sorts::BitVector(16),
-                    "i32" | "u32" => bitvec::Sorts::BitVector(32),
-                    "i64" | "u64" => bitvec::Sorts::BitVector(64),
-                    _ => {
+                let sort = match gen_sort(v.var_type.as_str(), pointer_width) {
+                    Some(sort) => sort,
+                    None => {
                         rp_error!(
                             "Invalid or Unsupported type for variable: \"{}\" : \"{}\"",
                             v.name,
@@ -242,7 +300,104 @@ impl Pred2SMT for SMTLib2<QF_ABV> {
             },
             Expression::SignedBitVector (ref s) => {
                 return bv_const!(self, s.value as u64, s.size as usize);
-            }
+            },
+            Expression::VariantTest (_) => {
+                rp_error!("Unresolved enum predicate in verification condition: {}", vc);
+            },
+            Expression::IntegerLiteral (_) => {
+                rp_error!("Untyped integer literal in verification condition: {}", vc);
+            },
+            Expression::Cast (ref c) => {
+                let e = self.expr2smtlib(c.e.as_ref(), pointer_width);
+                let from_type = determine_evaluation_type(c.e.as_ref());
+                let to_size = match type_size(c.ty.as_str(), pointer_width) {
+                    Some(size) => size as u64,
+                    None => rp_error!("Invalid or Unsupported cast: {}", vc),
+                };
+                // true is 1, and false is 0
+                if from_type == "bool" {
+                    let one = bv_const!(self, 1, to_size as usize);
+                    let zero = bv_const!(self, 0, to_size as usize);
+                    return self.assert(core::OpCodes::ITE, &[e, one, zero]);
+                }
+                let from_size = match type_size(from_type.as_str(), pointer_width) {
+                    Some(size) => size as u64,
+                    None => rp_error!("Invalid or Unsupported cast: {}", vc),
+                };
+                if to_size < from_size {
+                    // Truncate to the low bits
+                    return self.assert(bitvec::OpCodes::Extract(to_size - 1, 0), &[e]);
+                } else if to_size > from_size {
+                    // Extend according to the signedness of the source
+                    if from_type.starts_with('i') {
+                        return self.assert(bitvec::OpCodes::SignExtend(to_size - from_size), &[e]);
+                    } else {
+                        return self.assert(bitvec::OpCodes::ZeroExtend(to_size - from_size), &[e]);
+                    }
+                } else {
+                    // Same width; only the interpretation of the bits changes
+                    return e;
+                }
+            },
+            Expression::Select (ref s) => {
+                let a = self.expr2smtlib(s.array.as_ref(), pointer_width);
+                let i = self.expr2smtlib(s.index.as_ref(), pointer_width);
+                return self.assert(array_ex::OpCodes::Select, &[a, i]);
+            },
+            Expression::Store (ref s) => {
+                let a = self.expr2smtlib(s.array.as_ref(), pointer_width);
+                let i = self.expr2smtlib(s.index.as_ref(), pointer_width);
+                let v = self.expr2smtlib(s.value.as_ref(), pointer_width);
+                return self.assert(array_ex::OpCodes::Store, &[a, i, v]);
+            },
         }
     }
 }
+
+// Resizes the shift amount to the width of the value being shifted. Truncating is safe because
+// the shift amount is checked to be less than that width.
+fn gen_shift_amount(solver: &mut SMTLib2<QF_ABV>,
+                    b: &BinaryExpressionData,
+                    r: NodeIndex,
+                    pointer_width: u8)
+                    -> NodeIndex {
+    let l_type = determine_evaluation_type(b.left.as_ref());
+    let r_type = determine_evaluation_type(b.right.as_ref());
+    let (l_size, r_size) = match (type_size(l_type.as_str(), pointer_width),
+                                 type_size(r_type.as_str(), pointer_width)) {
+        (Some(l_size), Some(r_size)) => (l_size as u64, r_size as u64),
+        _ => rp_error!("Invalid or Unsupported shift: {} {} {}", l_type, b.op, r_type),
+    };
+    if r_size > l_size {
+        solver.assert(bitvec::OpCodes::Extract(l_size - 1, 0), &[r])
+    } else if r_size < l_size {
+        solver.assert(bitvec::OpCodes::ZeroExtend(l_size - r_size), &[r])
+    } else {
+        r
+    }
+}
+
+// Finds the sort of a type; arrays and slices are arrays indexed by usize
+fn gen_sort(var_type: &str, pointer_width: u8) -> Option<QF_ABV_Sorts> {
+    if var_type == "bool" {
+        return Some(bitvec::Sorts::Bool.into());
+    }
+    if let Some(size) = type_size(var_type, pointer_width) {
+        return Some(bitvec::Sorts::BitVector(size as usize).into());
+    }
+    match array_element_type(var_type) {
+        Some(element) => {
+            let index = match gen_sort("usize", pointer_width) {
+                Some(index) => index,
+                None => return None,
+            };
+            match gen_sort(element.as_str(), pointer_width) {
+                Some(element) => {
+                    Some(array_ex::Sorts::Array(Box::new(index), Box::new(element)).into())
+                },
+                None => None,
+            }
+        },
+        None => None,
+    }
+}
@@ -89,6 +89,126 @@ fn test_assertion_examples(){
     assert!(test_example_file("test_assert"));
 }
 
+// Test example for loop invariant examples
+#[test]
+fn test_loop_examples(){
+    assert!(test_example_file("test_loops"));
+}
+
+// Test example for bounded loop unrolling examples
+#[test]
+fn test_unroll_examples(){
+    assert!(test_example_file("test_unroll"));
+}
+
+// Test example for integer match examples
+#[test]
+fn test_switch_examples(){
+    assert!(test_example_file("test_switch"));
+}
+
+// Test example for enum examples
+#[test]
+fn test_enum_examples(){
+    assert!(test_example_file("test_enums"));
+}
+
+// Test example for call examples
+#[test]
+fn test_call_examples(){
+    assert!(test_example_file("test_calls"));
+}
+
+// Test example for inlining examples
+#[test]
+fn test_inline_examples(){
+    assert!(test_example_file("test_inline"));
+}
+
+// Test example for cast examples
+#[test]
+fn test_cast_examples(){
+    assert!(test_example_file("test_casts"));
+}
+
+// Test example for reference examples
+#[test]
+fn test_reference_examples(){
+    assert!(test_example_file("test_references"));
+}
+
+// Test example for struct examples
+#[test]
+fn test_struct_examples(){
+    assert!(test_example_file("test_structs"));
+}
+
+// Test example for array examples
+#[test]
+fn test_array_examples(){
+    assert!(test_example_file("test_arrays"));
+}
+
+// Test example for run-time check examples
+#[test]
+fn test_runtime_check_examples(){
+    assert!(test_example_file("test_runtime_checks"));
+}
+
+// Test example for overflow mode examples
+#[test]
+fn test_overflow_mode_examples(){
+    assert!(test_example_file("test_overflow_modes"));
+}
+
+// Test example for drop examples
+#[test]
+fn test_drop_examples(){
+    assert!(test_example_file("test_drops"));
+}
+
+// Test example for constant examples
+#[test]
+fn test_const_examples(){
+    assert!(test_example_file("test_consts"));
+}
+
+// Test example for static examples
+#[test]
+fn test_static_examples(){
+    assert!(test_example_file("test_statics"));
+}
+
+// Test example for char examples
+#[test]
+fn test_char_examples(){
+    assert!(test_example_file("test_chars"));
+}
+
+// Test example for pointer width examples
+#[test]
+fn test_pointer_width_examples(){
+    assert!(test_example_file("test_pointer_width"));
+}
+
+// Test example for old value examples
+#[test]
+fn test_old_value_examples(){
+    assert!(test_example_file("test_old_values"));
+}
+
+// Test example for inferred type examples
+#[test]
+fn test_inferred_type_examples(){
+    assert!(test_example_file("test_inferred_types"));
+}
+
+// Test example for literal examples
+#[test]
+fn test_literal_examples(){
+    assert!(test_example_file("test_literals"));
+}
+
 #[test]
 #[should_panic]
 fn test_system_test_validity() {
@@ -236,3 +236,316 @@ fn variable_mapping_data_equality() {
     assert!(var1 != var6);
     assert!(var6 == var6);
 }
+
+#[test]
+fn rename_variable_and_fields() {
+    let mut p: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Equal,
+        left: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "tmp1".to_string(),
+            var_type: "i32".to_string()
+        })),
+        right: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "tmp1.0".to_string(),
+            var_type: "i32".to_string()
+        })),
+    });
+    // Neither a different variable, nor one sharing the prefix, should be renamed
+    let untouched: Expression = Expression::VariableMapping( VariableMappingData {
+        name: "tmp10".to_string(),
+        var_type: "i32".to_string()
+    });
+
+    let correct_result: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Equal,
+        left: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "tmp1!bb2".to_string(),
+            var_type: "i32".to_string()
+        })),
+        right: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "tmp1!bb2.0".to_string(),
+            var_type: "i32".to_string()
+        })),
+    });
+    rename_variable(&mut p, "tmp1", "tmp1!bb2");
+    assert_eq!(p, correct_result);
+
+    let mut q = untouched.clone();
+    rename_variable(&mut q, "tmp1", "tmp1!bb2");
+    assert_eq!(q, untouched);
+}
+
+#[test]
+fn cast_evaluation_type_and_ty_check() {
+    let x: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "x".to_string(),
+        var_type: "i8".to_string()
+    });
+    let widened: Expression = Expression::Cast( CastData{
+        e: Box::new(x.clone()),
+        ty: "u64".to_string(),
+    });
+    assert_eq!(determine_evaluation_type(&widened), "u64");
+    assert!(ty_check(&widened).is_ok());
+
+    // Casting to a non-integer type is an error
+    let to_bool: Expression = Expression::Cast( CastData{
+        e: Box::new(x.clone()),
+        ty: "bool".to_string(),
+    });
+    assert!(ty_check(&to_bool).is_err());
+
+    // Booleans can be cast to integers, but arrays cannot
+    let from_bool: Expression = Expression::Cast( CastData{
+        e: Box::new(Expression::BooleanLiteral(true)),
+        ty: "u8".to_string(),
+    });
+    assert!(ty_check(&from_bool).is_ok());
+    let from_array: Expression = Expression::Cast( CastData{
+        e: Box::new(Expression::VariableMapping( VariableMappingData{
+            name: "a".to_string(),
+            var_type: "[u32; 4]".to_string()
+        })),
+        ty: "u32".to_string(),
+    });
+    assert!(ty_check(&from_array).is_err());
+
+    // Substitution reaches inside the cast
+    let mut p = widened.clone();
+    let y: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "y".to_string(),
+        var_type: "i8".to_string()
+    });
+    substitute_variable_with_expression(&mut p,
+                                        &VariableMappingData{ name: "x".to_string(),
+                                                              var_type: "i8".to_string() },
+                                        &y);
+    assert_eq!(p, Expression::Cast( CastData{ e: Box::new(y), ty: "u64".to_string() }));
+}
+
+#[test]
+fn array_types_and_select_ty_check() {
+    assert_eq!(array_element_type("[u32; 4]"), Some("u32".to_string()));
+    assert_eq!(array_element_type("[i8]"), Some("i8".to_string()));
+    assert_eq!(array_element_type("[[u8; 2]; 3]"), Some("[u8; 2]".to_string()));
+    assert_eq!(array_element_type("u32"), None);
+    assert_eq!(array_length("[u32; 4]"), Some(4));
+    assert_eq!(array_length("[u32]"), None);
+
+    let a: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "a".to_string(),
+        var_type: "[u32; 4]".to_string()
+    });
+    let i: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "i".to_string(),
+        var_type: "usize".to_string()
+    });
+    let select: Expression = Expression::Select( SelectData{
+        array: Box::new(a.clone()),
+        index: Box::new(i.clone()),
+    });
+    assert_eq!(determine_evaluation_type(&select), "u32");
+    assert!(ty_check(&select).is_ok());
+
+    // Arrays are indexed by usize
+    let bad_index: Expression = Expression::Select( SelectData{
+        array: Box::new(a.clone()),
+        index: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData{
+            size: 32,
+            value: 1
+        })),
+    });
+    assert!(ty_check(&bad_index).is_err());
+
+    // Stored values must have the type of the elements
+    let bad_store: Expression = Expression::Store( StoreData{
+        array: Box::new(a.clone()),
+        index: Box::new(i.clone()),
+        value: Box::new(Expression::BooleanLiteral(true)),
+    });
+    assert!(ty_check(&bad_store).is_err());
+}
+
+#[test]
+fn float_variables_ty_check() {
+    // Floating-point values cannot be verified, so they are rejected
+    let x: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "x".to_string(),
+        var_type: "f32".to_string()
+    });
+    assert!(ty_check(&x).is_err());
+}
+
+#[test]
+fn char_literals_and_casts_ty_check() {
+    let a: Expression = Expression::Cast( CastData{
+        e: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData{
+            size: 32,
+            value: 'a' as u64
+        })),
+        ty: "char".to_string(),
+    });
+    assert_eq!(determine_evaluation_type(&a), "char");
+    assert!(ty_check(&a).is_ok());
+
+    // Surrogates are not chars
+    let surrogate: Expression = Expression::Cast( CastData{
+        e: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData{
+            size: 32,
+            value: 0xD800
+        })),
+        ty: "char".to_string(),
+    });
+    assert!(ty_check(&surrogate).is_err());
+
+    // Only a u8 can be cast to a char
+    let c: Expression = Expression::VariableMapping( VariableMappingData{
+        name: "c".to_string(),
+        var_type: "char".to_string()
+    });
+    let from_u32: Expression = Expression::Cast( CastData{
+        e: Box::new(Expression::VariableMapping( VariableMappingData{
+            name: "x".to_string(),
+            var_type: "u32".to_string()
+        })),
+        ty: "char".to_string(),
+    });
+    assert!(ty_check(&from_u32).is_err());
+
+    // Chars can be compared, but not added
+    let compare: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::LessThan,
+        left: Box::new(c.clone()),
+        right: Box::new(a.clone()),
+    });
+    assert!(ty_check(&compare).is_ok());
+    let add: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Addition,
+        left: Box::new(c.clone()),
+        right: Box::new(a.clone()),
+    });
+    assert!(ty_check(&add).is_err());
+}
+
+#[test]
+fn rename_to_old_and_uses_old() {
+    // *x + a[i]
+    let mut e: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Addition,
+        left: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "*x".to_string(),
+            var_type: "u32".to_string()
+        })),
+        right: Box::new( Expression::Select( SelectData {
+            array: Box::new( Expression::VariableMapping( VariableMappingData {
+                name: "a".to_string(),
+                var_type: "[u32]".to_string()
+            })),
+            index: Box::new( Expression::VariableMapping( VariableMappingData {
+                name: "i".to_string(),
+                var_type: "usize".to_string()
+            })),
+        })),
+    });
+    assert!(!uses_old(&e));
+
+    // old(*x) + old(a)[old(i)]
+    let correct_result: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Addition,
+        left: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "old(*x)".to_string(),
+            var_type: "u32".to_string()
+        })),
+        right: Box::new( Expression::Select( SelectData {
+            array: Box::new( Expression::VariableMapping( VariableMappingData {
+                name: "old(a)".to_string(),
+                var_type: "[u32]".to_string()
+            })),
+            index: Box::new( Expression::VariableMapping( VariableMappingData {
+                name: "old(i)".to_string(),
+                var_type: "usize".to_string()
+            })),
+        })),
+    });
+    rename_to_old(&mut e);
+    assert_eq!(e, correct_result);
+    assert!(uses_old(&e));
+
+    // old(old(x)) is old(x)
+    rename_to_old(&mut e);
+    assert_eq!(e, correct_result);
+    assert_eq!(strip_old_name("old(*x)"), Some("*x"));
+    assert_eq!(strip_old_name("old"), None);
+}
+
+#[test]
+fn integer_literal_forms_and_ranges() {
+    assert_eq!(gen_integer_literal("0xFF", "u8"),
+               Ok(Expression::UnsignedBitVector( UnsignedBitVectorData{ size: 8, value: 255 })));
+    assert_eq!(gen_integer_literal("0b1010", "u8"),
+               Ok(Expression::UnsignedBitVector( UnsignedBitVectorData{ size: 8, value: 10 })));
+    assert_eq!(gen_integer_literal("0o17", "u16"),
+               Ok(Expression::UnsignedBitVector( UnsignedBitVectorData{ size: 16, value: 15 })));
+    assert_eq!(gen_integer_literal("1_000_000", "u64"),
+               Ok(Expression::UnsignedBitVector( UnsignedBitVectorData{
+                   size: 64,
+                   value: 1000000
+               })));
+    assert_eq!(gen_integer_literal("-128", "i8"),
+               Ok(Expression::SignedBitVector( SignedBitVectorData{ size: 8, value: -128 })));
+    assert_eq!(gen_integer_literal("-9223372036854775808", "i64"),
+               Ok(Expression::SignedBitVector( SignedBitVectorData{
+                   size: 64,
+                   value: i64::min_value()
+               })));
+
+    // Values out of the range of their type are errors, not panics
+    assert!(gen_integer_literal("300", "u8").is_err());
+    assert!(gen_integer_literal("128", "i8").is_err());
+    assert!(gen_integer_literal("-1", "u32").is_err());
+    assert!(gen_integer_literal("18446744073709551616", "u64").is_err());
+    assert!(gen_integer_literal("5", "bool").is_err());
+}
+
+#[test]
+fn infer_literal_types_from_operands() {
+    // x < 5, with x: i32
+    let mut e: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::LessThan,
+        left: Box::new( Expression::VariableMapping( VariableMappingData {
+            name: "x".to_string(),
+            var_type: "i32".to_string()
+        })),
+        right: Box::new( Expression::IntegerLiteral( IntegerLiteralData {
+            text: "5".to_string(),
+            ty: None
+        })),
+    });
+    assert!(ty_check(&e).is_err());
+    assert!(infer_literal_types(&mut e).is_ok());
+    assert!(ty_check(&e).is_ok());
+    if let Expression::BinaryExpression(ref b) = e {
+        assert_eq!(*b.right,
+                   Expression::SignedBitVector( SignedBitVectorData{ size: 32, value: 5 }));
+    }
+
+    // 5 == 5 has no type to infer
+    let literal = Expression::IntegerLiteral( IntegerLiteralData {
+        text: "5".to_string(),
+        ty: None
+    });
+    let mut untyped: Expression = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::Equal,
+        left: Box::new(literal.clone()),
+        right: Box::new(literal),
+    });
+    assert!(infer_literal_types(&mut untyped).is_ok());
+    assert!(ty_check(&untyped).is_err());
+
+    // 300u8 is out of range
+    let mut out_of_range = Expression::IntegerLiteral( IntegerLiteralData {
+        text: "300".to_string(),
+        ty: Some("u8".to_string())
+    });
+    assert!(infer_literal_types(&mut out_of_range).is_err());
+}
@@ -0,0 +1,300 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to model enums as a discriminant plus the fields of each variant, and structs as one
+//! variable per field, and to replace the enum predicates in conditions with tests of the
+//! discriminant.
+
+use super::super::MirData;
+use super::conditions::find_ty;
+use super::statics::gen_static_types;
+use super::gen_deref_name;
+use std::process;
+use expression::*;
+use rustc::ty::{TyCtxt, Ty, TypeVariants};
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Returns the variable holding the discriminant of an enum.
+///
+/// # Arguments:
+/// * `var` - The enum variable.
+///
+/// # Return Value:
+/// * Returns a `u32` variable named `<var>.discr`, which holds the index of the enum's variant.
+///
+/// # Remarks:
+///
+pub fn gen_discriminant(var: &VariableMappingData) -> VariableMappingData {
+    VariableMappingData {
+        name: format!("{}.discr", var.name),
+        var_type: "u32".to_string()
+    }
+}
+
+/// Returns the value of an enum's discriminant for a given variant.
+///
+/// # Arguments:
+/// * `variant` - The index of the variant within the enum's definition.
+///
+/// # Remarks:
+///
+pub fn gen_variant_index(variant: usize) -> Expression {
+    Expression::UnsignedBitVector( UnsignedBitVectorData {
+        size: 32,
+        value: variant as u64
+    } )
+}
+
+/// Returns the name of the variable holding a field of an enum variant.
+///
+/// # Arguments:
+/// * `base` - The name of the enum variable.
+/// * `variant` - The name of the variant.
+/// * `field` - The name of the field; tuple variants use the position of the field.
+///
+/// # Remarks:
+/// * The fields of each variant are distinct variables, named `<base>.<variant>.<field>`.
+///
+pub fn gen_field_name(base: &str, variant: &str, field: &str) -> String {
+    format!("{}.{}.{}", base, variant, field)
+}
+
+/// Replaces each enum predicate (ie: `x is Some(v)`) in a condition with a test of the enum's
+/// discriminant.
+///
+/// # Arguments:
+/// * `condition` - The condition containing the predicates.
+/// * `types` - The name and type of each variable that may appear in the condition.
+/// * `tcx` - The type context, used to find the types of the fields of each variant.
+/// * `bindings` - Collects each name bound by a predicate, with the variable holding the field
+///                it is bound to.
+///
+/// # Remarks:
+/// * Bound names are visible throughout the whole condition, so they must be unique and must not
+///   shadow an argument.
+///
+pub fn replace_variant_tests<'a, 'tcx>(condition: &mut Expression,
+                                   types: &[(String, Ty<'tcx>)],
+                                   tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                   bindings: &mut Vec<(String, VariableMappingData)>) {
+    let test = match *condition {
+        Expression::BinaryExpression(ref mut b) => {
+            replace_variant_tests(&mut b.left, types, tcx, bindings);
+            replace_variant_tests(&mut b.right, types, tcx, bindings);
+            return;
+        },
+        Expression::UnaryExpression(ref mut u) => {
+            replace_variant_tests(&mut u.e, types, tcx, bindings);
+            return;
+        },
+        Expression::Cast(ref mut c) => {
+            replace_variant_tests(&mut c.e, types, tcx, bindings);
+            return;
+        },
+        Expression::Select(ref mut s) => {
+            replace_variant_tests(&mut s.index, types, tcx, bindings);
+            return;
+        },
+        Expression::VariantTest(ref v) => v.clone(),
+        _ => return,
+    };
+
+    // A test of old(x) is a test of the discriminant and fields of x on entry
+    let (base, old) = match strip_old_name(test.name.as_str()) {
+        Some(name) => (name.to_string(), true),
+        None => (test.name.clone(), false),
+    };
+    let entry = |name: String| if old { gen_old_name(name.as_str()) } else { name };
+
+    let ty = match find_ty(base.as_str(), types) {
+        Some(ty) => ty,
+        None => rp_error!("Unknown variable in enum predicate: {}", condition),
+    };
+    let (adt_def, substs) = match ty.sty {
+        TypeVariants::TyEnum(adt_def, substs) => (adt_def, substs),
+        _ => rp_error!("Enum predicate on a variable of type {}: {}", ty, condition),
+    };
+    let index = match adt_def.variants.iter()
+                             .position(|v| &*v.name.as_str() == test.variant.as_str()) {
+        Some(index) => index,
+        None => rp_error!("{} has no variant named {}: {}", ty, test.variant, condition),
+    };
+    let variant = &adt_def.variants[index];
+
+    if !test.bindings.is_empty() && test.bindings.len() != variant.fields.len() {
+        rp_error!("{}::{} has {} field(s), but {} name(s) were bound: {}",
+                  ty, test.variant, variant.fields.len(), test.bindings.len(), condition);
+    }
+    for (binding, field) in test.bindings.iter().zip(variant.fields.iter()) {
+        if binding == "return" || find_ty(binding.as_str(), types).is_some()
+           || bindings.iter().any(|&(ref b, _)| b == binding) {
+            rp_error!("The name {} is already in use: {}", binding, condition);
+        }
+        bindings.push((binding.clone(), VariableMappingData {
+            name: entry(gen_field_name(base.as_str(), &variant.name.as_str(),
+                                       &field.name.as_str())),
+            var_type: field.ty(tcx, substs).to_string()
+        }));
+    }
+
+    // x.discr == index
+    let discriminant = VariableMappingData { name: base, var_type: ty.to_string() };
+    let mut discriminant = gen_discriminant(&discriminant);
+    discriminant.name = entry(discriminant.name);
+    *condition = Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::Equal,
+        left: Box::new(Expression::VariableMapping(discriminant)),
+        right: Box::new(gen_variant_index(index))
+    } );
+}
+
+/// Returns the name of a field of a struct or tuple.
+///
+/// # Arguments:
+/// * `ty` - The type of the struct or tuple.
+/// * `field` - The position of the field.
+///
+/// # Remarks:
+/// * The fields of a struct are distinct variables, named `<base>.<field>`. Tuple fields, and the
+///   fields of anything that is not a struct, are named by their position.
+///
+pub fn gen_struct_field_name(ty: Ty, field: usize) -> String {
+    match ty.sty {
+        TypeVariants::TyStruct(adt_def, _) => {
+            adt_def.struct_variant().fields[field].name.as_str().to_string()
+        },
+        _ => field.to_string(),
+    }
+}
+
+/// Assumes that the discriminant of each enum argument and static names one of its variants.
+///
+/// # Arguments:
+/// * `vc` - The verification condition.
+/// * `data` - Contains the argument declarations and the type context from the MIR pass.
+///
+/// # Return Value:
+/// * Returns `(d_1 < n_1) -> .. -> (d_k < n_k) -> vc`, for each discriminant `d_i` in `vc` of an
+///   enum with `n_i` variants.
+///
+/// # Remarks:
+/// * A discriminant is a `u32`, but the value the function is called with is always one of the
+///   enum's variants. This covers enums behind references, and in the fields of structs, tuples,
+///   and variants, as well as entry values, which `old(..)` has been resolved to by now.
+/// * Discriminants the function computes itself are already constrained by the body.
+///
+pub fn gen_discriminant_invariants(vc: &Expression, data: &MirData) -> Expression {
+    let mut discriminants = Vec::new();
+    find_discriminants(vc, &mut discriminants);
+
+    let mut roots = Vec::new();
+    for arg in data.arg_data.iter() {
+        roots.push((data.prefix.clone() + &arg.debug_name.as_str(), arg.ty));
+    }
+    roots.extend(gen_static_types(data));
+
+    let mut bounds = Vec::new();
+    for (name, ty) in roots {
+        gen_discriminant_bounds(name, ty, &discriminants, data, &mut bounds);
+    }
+
+    let mut vc = vc.clone();
+    for bound in bounds.iter().rev() {
+        vc = Expression::BinaryExpression( BinaryExpressionData {
+            op: BinaryOperator::Implication,
+            left: Box::new(bound.clone()),
+            right: Box::new(vc)
+        } );
+    }
+    vc
+}
+
+// Collects the bounds on the discriminant of an enum, and of the enums within a value, that
+// appear in the verification condition
+fn gen_discriminant_bounds<'a, 'tcx>(name: String,
+                                     ty: Ty<'tcx>,
+                                     discriminants: &[String],
+                                     data: &MirData<'a, 'tcx>,
+                                     bounds: &mut Vec<Expression>) {
+    // Only the parts of a value that the verification condition names are followed
+    if !discriminants.iter().any(|d| d.starts_with(name.as_str())) {
+        return;
+    }
+    match ty.sty {
+        // x.discr < number of variants
+        TypeVariants::TyEnum(adt_def, substs) => {
+            let var = VariableMappingData { name: name.clone(), var_type: ty.to_string() };
+            let discriminant = gen_discriminant(&var);
+            if discriminants.contains(&discriminant.name) {
+                bounds.push(Expression::BinaryExpression( BinaryExpressionData {
+                    op: BinaryOperator::LessThan,
+                    left: Box::new(Expression::VariableMapping(discriminant)),
+                    right: Box::new(gen_variant_index(adt_def.variants.len()))
+                } ));
+            }
+            for variant in adt_def.variants.iter() {
+                for field in variant.fields.iter() {
+                    let field_name = gen_field_name(name.as_str(), &variant.name.as_str(),
+                                                    &field.name.as_str());
+                    gen_discriminant_bounds(field_name, field.ty(data.tcx, substs),
+                                            discriminants, data, bounds);
+                }
+            }
+        },
+        TypeVariants::TyStruct(adt_def, substs) => {
+            for field in adt_def.struct_variant().fields.iter() {
+                let field_name = name.clone() + "." + &field.name.as_str();
+                gen_discriminant_bounds(field_name, field.ty(data.tcx, substs),
+                                        discriminants, data, bounds);
+            }
+        },
+        TypeVariants::TyTuple(fields) => {
+            for (index, &field_ty) in fields.iter().enumerate() {
+                gen_discriminant_bounds(format!("{}.{}", name, index), field_ty,
+                                        discriminants, data, bounds);
+            }
+        },
+        TypeVariants::TyRef(_, mt) => {
+            gen_discriminant_bounds(gen_deref_name(name.as_str()), mt.ty, discriminants, data,
+                                    bounds);
+        },
+        _ => {},
+    }
+}
+
+// Collects the names of the discriminants in an expression, without duplicates
+fn find_discriminants(expression: &Expression, discriminants: &mut Vec<String>) {
+    match *expression {
+        Expression::BinaryExpression(ref b) => {
+            find_discriminants(&b.left, discriminants);
+            find_discriminants(&b.right, discriminants);
+        },
+        Expression::UnaryExpression(ref u) => find_discriminants(&u.e, discriminants),
+        Expression::Cast(ref c) => find_discriminants(&c.e, discriminants),
+        Expression::Select(ref s) => {
+            find_discriminants(&s.array, discriminants);
+            find_discriminants(&s.index, discriminants);
+        },
+        Expression::Store(ref s) => {
+            find_discriminants(&s.array, discriminants);
+            find_discriminants(&s.index, discriminants);
+            find_discriminants(&s.value, discriminants);
+        },
+        Expression::VariableMapping(ref v) => {
+            if v.name.ends_with(".discr") && !discriminants.contains(&v.name) {
+                discriminants.push(v.name.clone());
+            }
+        },
+        _ => {},
+    }
+}
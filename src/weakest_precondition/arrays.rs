@@ -0,0 +1,230 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to model arrays and slices as SMT arrays indexed by `usize`, plus a length.
+
+use super::super::MirData;
+use super::{gen_lvalue, gen_expression};
+use std::process;
+use expression::*;
+use rustc::mir::repr::*;
+use rustc_const_math::ConstUsize;
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Finds the array and the index of an lvalue that is an element of an array or slice.
+///
+/// # Arguments:
+/// * `lvalue` - The lvalue, such as `a[i]`.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns the array variable and the index, or `None` if `lvalue` is not an element.
+///
+/// # Remarks:
+/// * Elements are not variables of their own; they are read with `Select` and written with
+///   `Store` on the array variable.
+///
+pub fn gen_index(lvalue: &Lvalue, data: &mut MirData) -> Option<(VariableMappingData, Expression)> {
+    if let Lvalue::Projection(ref pro) = *lvalue {
+        if let ProjectionElem::Index(ref index) = pro.elem {
+            check_element_base(&pro.base);
+            let array = gen_lvalue(pro.base.clone(), data);
+            let index = gen_expression(index, data);
+            return Some((array, index));
+        }
+    }
+    None
+}
+
+/// Stops verification if an lvalue is projected from an element of an array.
+///
+/// # Arguments:
+/// * `base` - The base the lvalue is projected from.
+///
+/// # Remarks:
+/// * Elements are only read and written whole, through the array. A path within an element
+///   (ie: `a[i].x`, `a[i][j]`, or `*a[i]`) would be a single variable shared by every element
+///   of the array, which is unsound.
+///
+pub fn check_element_base(base: &Lvalue) {
+    if let Lvalue::Projection(ref pro) = *base {
+        if let ProjectionElem::Index(_) = pro.elem {
+            rp_error!("Unsupported lvalue: {:?} is an element of an array, and only whole \
+                       elements can be read or written, not paths within them (ie: a[i].x or \
+                       a[i][j]).", base);
+        }
+    }
+}
+
+/// Returns the element of an array at an index.
+///
+/// # Arguments:
+/// * `array` - The array variable.
+/// * `index` - The `usize` index of the element.
+///
+/// # Remarks:
+///
+pub fn gen_select(array: &VariableMappingData, index: &Expression) -> Expression {
+    Expression::Select( SelectData {
+        array: Box::new(Expression::VariableMapping(array.clone())),
+        index: Box::new(index.clone())
+    } )
+}
+
+/// Returns an array with the element at an index replaced.
+///
+/// # Arguments:
+/// * `array` - The array variable.
+/// * `index` - The `usize` index of the element.
+/// * `value` - The new value of the element.
+///
+/// # Remarks:
+/// * wp(a[i] = x) => wp[a := store(a, i, x)]
+///
+pub fn gen_store(array: &VariableMappingData, index: &Expression, value: &Expression)
+                 -> Expression {
+    Expression::Store( StoreData {
+        array: Box::new(Expression::VariableMapping(array.clone())),
+        index: Box::new(index.clone()),
+        value: Box::new(value.clone())
+    } )
+}
+
+/// Returns the length of an array or slice.
+///
+/// # Arguments:
+/// * `array` - The array variable.
+///
+/// # Return Value:
+/// * Returns a `usize` constant for a fixed-size array, or the `usize` variable named
+///   `<array>.len` for a slice.
+///
+/// # Remarks:
+///
+pub fn gen_len(array: &VariableMappingData) -> Expression {
+    match array_length(array.var_type.as_str()) {
+        Some(len) => gen_usize(len),
+        None => {
+            Expression::VariableMapping( VariableMappingData {
+                name: format!("{}.len", array.name),
+                var_type: "usize".to_string()
+            } )
+        },
+    }
+}
+
+/// Returns a `usize` constant, such as an index or a length.
+///
+/// # Arguments:
+/// * `value` - The value of the constant.
+///
+/// # Remarks:
+/// * The constant is written as a 64-bit literal, which is truncated to the pointer width.
+///
+pub fn gen_usize(value: u64) -> Expression {
+    Expression::Cast( CastData {
+        e: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData {
+            size: 64,
+            value: value
+        } )),
+        ty: "usize".to_string()
+    } )
+}
+
+/// Returns the value of a `usize` constant from MIR.
+///
+/// # Arguments:
+/// * `value` - The constant.
+///
+/// # Remarks:
+///
+pub fn gen_const_usize(value: &ConstUsize) -> u64 {
+    match *value {
+        ConstUsize::Us16(n) => n as u64,
+        ConstUsize::Us32(n) => n as u64,
+        ConstUsize::Us64(n) => n,
+    }
+}
+
+/// Computes the weakest precondition of an assignment of an array with every element the same.
+///
+/// # Arguments:
+/// * `wp` - The weakest precondition after the assignment, with the array renamed to `array`.
+/// * `array` - The array variable, a fresh name standing for the new array.
+/// * `value` - The value of every element.
+///
+/// # Return Value:
+/// * Returns `(array[i_1] == value) -> .. -> (array[i_n] == value) -> wp`, for each index `i_k`
+///   of an element read from `array` in `wp`.
+///
+/// # Remarks:
+/// * wp(x = [v; n]) => (forall k. x'[k] == v) -> wp[x := x'], where x' is fresh. The quantifier
+///   is only needed at the indices `wp` reads, so the formula stays quantifier-free, and its size
+///   does not depend on `n`.
+/// * Reads through later stores into the array (ie: `x[j] = w; x[i]`) are indices of `array`
+///   as well. Equality of whole arrays is not covered, so it is never proven for such an array.
+///
+pub fn gen_repeat(wp: &Expression, array: &VariableMappingData, value: &Expression)
+                  -> Expression {
+    let mut indices = Vec::new();
+    find_reads(wp, array.name.as_str(), &mut indices);
+
+    let mut wp = wp.clone();
+    for index in indices.iter().rev() {
+        wp = Expression::BinaryExpression( BinaryExpressionData {
+            op: BinaryOperator::Implication,
+            left: Box::new(Expression::BinaryExpression( BinaryExpressionData {
+                op: BinaryOperator::Equal,
+                left: Box::new(gen_select(array, index)),
+                right: Box::new(value.clone())
+            } )),
+            right: Box::new(wp)
+        } );
+    }
+    wp
+}
+
+// Finds the indices of the elements read from an array variable, directly or through stores
+fn find_reads(e: &Expression, array: &str, indices: &mut Vec<Expression>) {
+    match *e {
+        Expression::BinaryExpression(ref b) => {
+            find_reads(&b.left, array, indices);
+            find_reads(&b.right, array, indices);
+        },
+        Expression::UnaryExpression(ref u) => find_reads(&u.e, array, indices),
+        Expression::Cast(ref c) => find_reads(&c.e, array, indices),
+        Expression::Select(ref s) => {
+            if is_stored_into(&s.array, array) && !indices.contains(s.index.as_ref()) {
+                indices.push(s.index.as_ref().clone());
+            }
+            find_reads(&s.array, array, indices);
+            find_reads(&s.index, array, indices);
+        },
+        Expression::Store(ref s) => {
+            find_reads(&s.array, array, indices);
+            find_reads(&s.index, array, indices);
+            find_reads(&s.value, array, indices);
+        },
+        _ => {},
+    }
+}
+
+// Checks if an array expression is an array variable, with any number of stores into it
+fn is_stored_into(e: &Expression, array: &str) -> bool {
+    match *e {
+        Expression::VariableMapping(ref v) => v.name == array,
+        Expression::Store(ref s) => is_stored_into(&s.array, array),
+        _ => false,
+    }
+}
@@ -0,0 +1,94 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to find the places borrowed by the references of a function.
+
+use super::super::MirData;
+use super::gen_lvalue;
+use std::process;
+use std::collections::HashMap;
+use rustc::mir::repr::*;
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Finds the place each reference temp and variable of a function borrows.
+///
+/// # Arguments:
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns the borrowed place of each reference, keyed by the name of the reference.
+///
+/// # Remarks:
+/// * `r = &y` borrows `y`, and `r = &mut *x` reborrows the value behind `x`. Copying or moving a
+///   reference (`r = s`) borrows the place behind `s`.
+/// * `gen_lvalue` resolves `*r` to the borrowed place, so reads and writes through `r` are reads
+///   and writes of the place itself.
+/// * A reference must borrow the same place everywhere it is assigned, and may not borrow an
+///   element of an array, since the index could change while the element is borrowed.
+/// * References in arguments, fields, and the return value are not tracked; the value behind
+///   each is its own variable, `*<name>`.
+///
+pub fn find_borrows<'a, 'tcx>(data: &mut MirData<'a, 'tcx>) -> HashMap<String, Lvalue<'tcx>> {
+    let mut borrows: HashMap<String, Lvalue<'tcx>> = HashMap::new();
+    let blocks = data.block_data.clone();
+    for block in blocks.iter() {
+        for stmt in block.statements.iter() {
+            let (lvalue, rvalue) = match stmt.kind {
+                StatementKind::Assign(ref lvalue, ref rvalue) => (lvalue, rvalue),
+            };
+            let reference = gen_lvalue(lvalue.clone(), data);
+            let place = match *rvalue {
+                Rvalue::Ref(_, _, ref place) => place.clone(),
+                Rvalue::Use(Operand::Consume(ref source))
+                    if reference.var_type.starts_with('&') => source.clone().deref(),
+                _ => continue,
+            };
+            match *lvalue {
+                Lvalue::Temp(_) | Lvalue::Var(_) => {},
+                Lvalue::Arg(_) => {
+                    rp_error!("Unsupported borrow: the argument {} is assigned a new reference.",
+                              reference.name);
+                },
+                _ => continue,
+            }
+            if borrows_element(&place) {
+                rp_error!("Unsupported borrow: {} borrows an element of an array ({:?}).",
+                          reference.name, place);
+            }
+            if let Some(previous) = borrows.get(&reference.name) {
+                if *previous != place {
+                    rp_error!("Unsupported borrow: {} borrows both {:?} and {:?}.",
+                              reference.name, previous, place);
+                }
+            }
+            borrows.insert(reference.name, place);
+        }
+    }
+    borrows
+}
+
+// Checks if a place is, or is within, an element of an array
+fn borrows_element(place: &Lvalue) -> bool {
+    match *place {
+        Lvalue::Projection(ref pro) => {
+            match pro.elem {
+                ProjectionElem::Deref |
+                ProjectionElem::Field(..) |
+                ProjectionElem::Downcast(..) => borrows_element(&pro.base),
+                _ => true,
+            }
+        },
+        _ => false,
+    }
+}
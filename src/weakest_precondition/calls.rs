@@ -0,0 +1,407 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to generate the weakest precondition of a call from the callee's contract.
+
+use super::super::MirData;
+use super::{gen, gen_lvalue, gen_expression, gen_check, gen_ty, gen_deref_name,
+            gen_entry_values};
+use super::conditions::resolve_typed_condition;
+use super::loops::find_loop_headers;
+use super::statics::{find_mutable_statics, gen_static_types};
+use parser::{parse_attribute, parse_condition};
+use std::process;
+use std::mem;
+use expression::*;
+use rustc::mir::repr::*;
+use rustc::hir::{self, PatKind};
+use rustc::hir::def_id::DefId;
+use rustc::hir::map::Node;
+use rustc::ty::FnOutput;
+use rustc_data_structures::indexed_vec::Idx;
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// The pre- and postcondition of a callee, along with the names of its arguments.
+pub struct Contract {
+    pub pre: Expression,
+    pub post: Expression,
+    pub args: Vec<String>,
+}
+
+/// Finds the contract of a callee, as given by its `condition` attribute.
+///
+/// # Arguments:
+/// * `def_id` - The `DefId` of the callee.
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Return Value:
+/// * Returns the parsed contract, or `None` if the callee has no `condition` attribute.
+///
+/// # Remarks:
+/// * Only callees defined in the crate being verified have argument names available, so only
+///   they can have a contract.
+///
+pub fn find_contract(def_id: DefId, data: &MirData) -> Option<Contract> {
+    let mut pre_string = "".to_string();
+    let mut post_string = "".to_string();
+    for attr in data.tcx.get_attrs(def_id).iter() {
+        parse_attribute(&mut pre_string, &mut post_string, attr);
+    }
+    if pre_string == "" {
+        return None;
+    }
+
+    let name = data.tcx.item_path_str(def_id);
+    let args = match gen_arg_names(def_id, data) {
+        Some(args) => args,
+        None => rp_error!("The arguments of fn {}(..) could not be found.", name),
+    };
+
+    // The types of the callee's arguments, return value, and the statics, for its conditions
+    let sig = data.tcx.lookup_item_type(def_id).ty.fn_sig().skip_binder().clone();
+    let mut types = Vec::new();
+    if let FnOutput::FnConverging(ty) = sig.output {
+        types.push(("return".to_string(), ty));
+    }
+    for (arg, ty) in args.iter().zip(sig.inputs.iter()) {
+        types.push((arg.clone(), *ty));
+    }
+    types.extend(gen_static_types(data));
+
+    let mut pre = parse_condition(pre_string.as_str());
+    let mut post = parse_condition(post_string.as_str());
+    if uses_old(&pre) {
+        rp_error!("The precondition of fn {}(..) uses old(..), which is only allowed in a \
+                   postcondition: {}", name, pre);
+    }
+    resolve_typed_condition(&mut pre, &types, data.tcx);
+    resolve_typed_condition(&mut post, &types, data.tcx);
+
+    Some(Contract { pre: pre, post: post, args: args })
+}
+
+// Finds the names of the arguments of a function or method from its declaration
+fn gen_arg_names(def_id: DefId, data: &MirData) -> Option<Vec<String>> {
+    let node_id = match data.tcx.map.as_local_node_id(def_id) {
+        Some(node_id) => node_id,
+        None => return None,
+    };
+    let decl = match data.tcx.map.get(node_id) {
+        Node::NodeItem(item) => {
+            match item.node {
+                hir::ItemFn(ref decl, ..) => decl,
+                _ => return None,
+            }
+        },
+        Node::NodeImplItem(item) => {
+            match item.node {
+                hir::ImplItemKind::Method(ref sig, _) => &sig.decl,
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    let mut names = Vec::new();
+    for arg in decl.inputs.iter() {
+        match arg.pat.node {
+            PatKind::Binding(_, ref name, _) => names.push(name.node.as_str().to_string()),
+            _ => rp_error!("Arguments of functions with a contract must be simple names."),
+        }
+    }
+    Some(names)
+}
+
+/// Computes the weakest precondition of a call to a function with a contract.
+///
+/// # Arguments:
+/// * `index` - The index of the `BasicBlock` containing the call.
+/// * `name` - The name of the callee.
+/// * `contract` - The contract of the callee.
+/// * `args` - The operands passed to the callee.
+/// * `destination` - The lvalue the result is stored in, and the block control continues at.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+/// * `post_expr` - The current weakest precondition (originally the postcondition) as an Expression.
+///
+/// # Return Value:
+/// * Returns the weakest precondition of the call in the form of an Expression.
+///
+/// # Remarks:
+/// * wp(d = f(a)) => pre_f[a] AND (post_f[a, r] -> wp(target)[d := r]), where r is a fresh
+///   variable standing for the value returned by the callee. The precondition is a labeled check.
+/// * The places borrowed by mutable references passed to the callee (a local, or `*x` for a
+///   reborrow of the argument `x`), and every `static mut`, are also given fresh names after the
+///   call, constrained only by the postcondition.
+/// * A callee that never returns only needs its precondition to hold.
+///
+pub fn gen_call(index: usize,
+                name: String,
+                contract: Contract,
+                args: &Vec<Operand>,
+                destination: &Option<(Lvalue, BasicBlock)>,
+                data: &mut MirData,
+                post_expr: &Option<Expression>,
+                debug: bool)
+                -> Option<Expression> {
+    if args.len() != contract.args.len() {
+        rp_error!("fn {}(..) is called with {} argument(s), but its contract names {}.",
+                  name, args.len(), contract.args.len());
+    }
+
+    let mut pre = contract.pre;
+    let mut post = contract.post;
+
+    // The value returned by this call
+    data.call_count += 1;
+    let (result, target) = match *destination {
+        Some((ref lvalue, target)) => {
+            let dest = gen_lvalue(lvalue.clone(), data);
+            let fresh = format!("{}!call{}", dest.name, data.call_count);
+            (Some((dest.name, fresh)), Some(target))
+        },
+        None => (None, None),
+    };
+    if let Some((_, ref fresh)) = result {
+        rename_variable(&mut post, "return", fresh.as_str());
+    }
+
+    // The places borrowed by mutable references may be changed by the callee; in the
+    // postcondition they refer to the new values
+    let mut changed = Vec::new();
+    for (arg, operand) in contract.args.iter().zip(args.iter()) {
+        if let Some(place) = gen_mutable_borrow(operand, data) {
+            let fresh = format!("{}!call{}", place.name, data.call_count);
+            rename_variable(&mut post, gen_deref_name(arg.as_str()).as_str(), fresh.as_str());
+            changed.push((place.name, fresh));
+        }
+    }
+
+    // So may every static mut
+    for var in find_mutable_statics(data) {
+        let fresh = format!("{}!call{}", var.name, data.call_count);
+        rename_variable(&mut post, var.name.as_str(), fresh.as_str());
+        changed.push((var.name, fresh));
+    }
+
+    // In the postcondition, old(x) refers to the value before the call
+    gen_entry_values(&mut post);
+
+    // Bind the callee's arguments to the operands. The arguments are first given names that
+    // cannot appear in the caller, so that binding one cannot capture another.
+    for (position, arg) in contract.args.iter().enumerate() {
+        let placeholder = format!("arg!{}", position);
+        let arg_deref = gen_deref_name(arg.as_str());
+        let placeholder_deref = gen_deref_name(placeholder.as_str());
+        rename_variable(&mut pre, arg.as_str(), placeholder.as_str());
+        rename_variable(&mut post, arg.as_str(), placeholder.as_str());
+        rename_variable(&mut pre, arg_deref.as_str(), placeholder_deref.as_str());
+        rename_variable(&mut post, arg_deref.as_str(), placeholder_deref.as_str());
+    }
+    let placeholders: Vec<String> = (0..args.len()).map(|p| format!("arg!{}", p)).collect();
+    bind_args(&mut pre, &placeholders, args, data);
+    bind_args(&mut post, &placeholders, args, data);
+
+    let label = format!("precondition of fn {}(..) called from bb{}", name, index);
+    let pre_check = gen_check(pre, label, data);
+
+    let (dest, fresh, target) = match (result, target) {
+        (Some((dest, fresh)), Some(target)) => (dest, fresh, target),
+        // The callee diverges
+        _ => return Some(pre_check),
+    };
+
+    // The caller continues with the returned value in the destination
+    let mut wp_target = gen(target.index(), data, post_expr, debug).unwrap();
+    rename_variable(&mut wp_target, dest.as_str(), fresh.as_str());
+    for (place, fresh) in changed {
+        rename_variable(&mut wp_target, place.as_str(), fresh.as_str());
+    }
+
+    if debug {
+        println!("call to fn {}(..) at bb{:?} assumes\t{:?}\n", name, index, post);
+    }
+
+    Some(Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::And,
+        left: Box::new(pre_check),
+        right: Box::new(Expression::BinaryExpression( BinaryExpressionData {
+            op: BinaryOperator::Implication,
+            left: Box::new(post),
+            right: Box::new(wp_target)
+        } ))
+    } ))
+}
+
+// Binds each argument of a callee, by name, to the operand passed for it
+fn bind_args(expression: &mut Expression,
+             names: &[String],
+             args: &Vec<Operand>,
+             data: &mut MirData) {
+    for (name, operand) in names.iter().zip(args.iter()) {
+        match *operand {
+            // Moving an enum also moves its discriminant and fields, and the value behind a
+            // reference is the place it borrows
+            Operand::Consume(ref lvalue) => {
+                let source = gen_lvalue(lvalue.clone(), data);
+                let place = gen_lvalue(lvalue.clone().deref(), data);
+                rename_variable(expression, name.as_str(), source.name.as_str());
+                rename_variable(expression,
+                                gen_deref_name(name.as_str()).as_str(),
+                                place.name.as_str());
+            },
+            Operand::Constant(ref constant) => {
+                let var = VariableMappingData {
+                    name: name.clone(),
+                    var_type: constant.ty.to_string()
+                };
+                let value = gen_expression(operand, data);
+                substitute_variable_with_expression(expression, &var, &value);
+            },
+        }
+    }
+}
+
+/// Returns the place borrowed by the reference passed as an operand, if it is a mutable
+/// reference.
+///
+/// # Arguments:
+/// * `operand` - The operand passed to a callee.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Remarks:
+/// * The place is a local for a borrow of a local (see `borrows::find_borrows`), or `*x` for a
+///   reborrow of the argument `x`.
+///
+pub fn gen_mutable_borrow(operand: &Operand, data: &mut MirData) -> Option<VariableMappingData> {
+    match *operand {
+        Operand::Consume(ref lvalue) if gen_ty(operand, data).starts_with("&mut ") => {
+            Some(gen_lvalue(lvalue.clone().deref(), data))
+        },
+        _ => None,
+    }
+}
+
+/// Computes the weakest precondition of a call by inlining the callee's MIR.
+///
+/// # Arguments:
+/// * `index` - The index of the `BasicBlock` containing the call.
+/// * `def_id` - The `DefId` of the callee.
+/// * `name` - The name of the callee.
+/// * `args` - The operands passed to the callee.
+/// * `destination` - The lvalue the result is stored in, and the block control continues at.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+/// * `post_expr` - The current weakest precondition (originally the postcondition) as an Expression.
+///
+/// # Return Value:
+/// * Returns the weakest precondition of the call in the form of an Expression.
+///
+/// # Remarks:
+/// * wp(d = f(a)) => wp(body_f, wp(target)[d := r])[args_f := a], where every argument, temp,
+///   and variable of the callee (including its return value r) is given a fresh name.
+/// * Only callees defined in the crate are inlined, and only up to the inline depth. Recursive
+///   callees, and callees whose loops cannot be unrolled, must be given a contract instead.
+///
+pub fn gen_inlined_call(index: usize,
+                        def_id: DefId,
+                        name: String,
+                        args: &Vec<Operand>,
+                        destination: &Option<(Lvalue, BasicBlock)>,
+                        data: &mut MirData,
+                        post_expr: &Option<Expression>,
+                        debug: bool)
+                        -> Option<Expression> {
+    let depth = match data.inline_depth {
+        Some(depth) => depth,
+        None => needs_contract(&name, index, "inlining is disabled".to_string()),
+    };
+    if data.inline_stack.contains(&def_id) {
+        needs_contract(&name, index, "it is recursive".to_string());
+    }
+    if data.inline_stack.len() >= depth {
+        needs_contract(&name, index, format!("it is nested deeper than the inline depth of {}",
+                                             depth));
+    }
+    let mir_map = data.mir_map;
+    let mir = match mir_map.map.get(&def_id) {
+        Some(mir) => mir,
+        None => needs_contract(&name, index, "its MIR is not available".to_string()),
+    };
+
+    // Give everything in the callee a fresh name
+    data.call_count += 1;
+    let prefix = format!("inline{}!", data.call_count);
+    let mut callee = MirData::new(data.tcx, mir, mir_map, prefix.clone());
+    callee.unroll = data.unroll;
+    callee.inline_depth = data.inline_depth;
+    callee.overflow = data.overflow;
+    callee.pointer_width = data.pointer_width;
+    callee.inline_stack = data.inline_stack.clone();
+    callee.inline_stack.push(def_id);
+    callee.loop_headers = find_loop_headers(&callee);
+    if !callee.loop_headers.is_empty() && callee.unroll.is_none() {
+        needs_contract(&name, index,
+                       "it contains a loop, and no unroll bound was given".to_string());
+    }
+
+    let arg_names: Vec<String> = callee.arg_data.iter()
+        .map(|arg| prefix.clone() + &arg.debug_name.as_str())
+        .collect();
+
+    // The callee returns into the destination
+    let mut post_callee = match *destination {
+        Some((ref lvalue, target)) => {
+            let dest = gen_lvalue(lvalue.clone(), data);
+            let mut wp_target = gen(target.index(), data, post_expr, debug).unwrap();
+            let callee_return = prefix.clone() + "return";
+            rename_variable(&mut wp_target, dest.name.as_str(), callee_return.as_str());
+            wp_target
+        },
+        // The callee diverges
+        None => Expression::BooleanLiteral(true),
+    };
+
+    // The callee writes through the mutable references passed to it, so after the call the
+    // borrowed places hold the values behind the callee's arguments
+    for (arg, operand) in arg_names.iter().zip(args.iter()) {
+        if let Some(place) = gen_mutable_borrow(operand, data) {
+            rename_variable(&mut post_callee,
+                            place.name.as_str(),
+                            gen_deref_name(arg.as_str()).as_str());
+        }
+    }
+
+    if debug {
+        println!("inlining fn {}(..) at bb{:?} as {}\n", name, index, prefix);
+    }
+
+    // The callee shares the caller's checks and fresh names
+    callee.checks = mem::replace(&mut data.checks, Vec::new());
+    callee.call_count = data.call_count;
+    let mut wp = gen(0, &mut callee, &Some(post_callee), debug).unwrap();
+    data.checks = mem::replace(&mut callee.checks, Vec::new());
+    data.call_count = callee.call_count;
+
+    // Bind the callee's arguments to the operands
+    bind_args(&mut wp, &arg_names, args, data);
+
+    Some(wp)
+}
+
+// Reports that a callee must be given a contract to be verified
+fn needs_contract(name: &str, index: usize, reason: String) -> ! {
+    rp_error!("fn {}(..) needs a contract: it is called at bb{} without a condition attribute, \
+               but cannot be inlined because {}.", name, index, reason);
+}
@@ -0,0 +1,101 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to assume that every `char` is a valid Unicode scalar value.
+
+use expression::*;
+
+/// Assumes that the chars in a verification condition are Unicode scalar values.
+///
+/// # Arguments:
+/// * `vc` - The verification condition.
+///
+/// # Return Value:
+/// * Returns `valid(c_1) -> .. -> valid(c_n) -> vc`, for each char variable or char array element
+///   `c_i` in `vc`.
+///
+/// # Remarks:
+/// * A char is a 32-bit vector, but Rust guarantees that it is at most `0x10FFFF` and is not a
+///   surrogate, so only those values are considered.
+/// * Every char left in a verification condition is a value the function did not compute itself
+///   (an argument, a static, or the result of a call), so the assumption holds for all of them.
+///
+pub fn gen_char_invariants(vc: &Expression) -> Expression {
+    let mut chars = Vec::new();
+    find_chars(vc, &mut chars);
+
+    let mut vc = vc.clone();
+    for c in chars.iter().rev() {
+        vc = Expression::BinaryExpression( BinaryExpressionData {
+            op: BinaryOperator::Implication,
+            left: Box::new(gen_valid_char(c)),
+            right: Box::new(vc)
+        } );
+    }
+    vc
+}
+
+// (c as u32 <= 0x10FFFF) AND ((c as u32 < 0xD800) OR (c as u32 > 0xDFFF))
+fn gen_valid_char(c: &Expression) -> Expression {
+    let value = Expression::Cast( CastData {
+        e: Box::new(c.clone()),
+        ty: "u32".to_string()
+    } );
+    let compare = |op: BinaryOperator, bound: u64| {
+        Expression::BinaryExpression( BinaryExpressionData {
+            op: op,
+            left: Box::new(value.clone()),
+            right: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData {
+                size: 32,
+                value: bound
+            } ))
+        } )
+    };
+    Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::And,
+        left: Box::new(compare(BinaryOperator::LessThanOrEqual, 0x10FFFF)),
+        right: Box::new(Expression::BinaryExpression( BinaryExpressionData {
+            op: BinaryOperator::Or,
+            left: Box::new(compare(BinaryOperator::LessThan, 0xD800)),
+            right: Box::new(compare(BinaryOperator::GreaterThan, 0xDFFF))
+        } ))
+    } )
+}
+
+// Collects the char variables and char array elements of an expression, without duplicates
+fn find_chars(expression: &Expression, chars: &mut Vec<Expression>) {
+    match *expression {
+        Expression::BinaryExpression(ref b) => {
+            find_chars(&b.left, chars);
+            find_chars(&b.right, chars);
+        },
+        Expression::UnaryExpression(ref u) => find_chars(&u.e, chars),
+        Expression::Cast(ref c) => find_chars(&c.e, chars),
+        Expression::Select(ref s) => {
+            find_chars(&s.array, chars);
+            find_chars(&s.index, chars);
+            if array_element_type(determine_evaluation_type(&s.array).as_str())
+               == Some("char".to_string()) && !chars.contains(expression) {
+                chars.push(expression.clone());
+            }
+        },
+        Expression::Store(ref s) => {
+            find_chars(&s.array, chars);
+            find_chars(&s.index, chars);
+            find_chars(&s.value, chars);
+        },
+        Expression::VariableMapping(ref v) => {
+            if v.var_type == "char" && !chars.contains(expression) {
+                chars.push(expression.clone());
+            }
+        },
+        _ => {},
+    }
+}
@@ -0,0 +1,346 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to resolve the conditions a user writes (pre- and postconditions, loop invariants,
+//! and the contracts of callees) against the types of the variables they name.
+
+use super::super::MirData;
+use super::adt::replace_variant_tests;
+use super::arrays::gen_usize;
+use super::consts::resolve_consts;
+use super::statics::gen_static_types;
+use std::process;
+use expression::*;
+use rustc::ty::{TyCtxt, Ty, TypeVariants};
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Resolves a pre- or postcondition against the function's signature.
+///
+/// # Arguments:
+/// * `condition` - The precondition or postcondition.
+/// * `data` - Contains the argument declarations and the return type from the MIR pass.
+///
+/// # Remarks:
+/// * The condition may name the arguments, `return`, and the statics of the crate.
+/// * See `resolve_typed_condition`.
+///
+pub fn resolve_condition(condition: &mut Expression, data: &MirData) {
+    let mut types = Vec::new();
+    if let Some(ty) = data.return_ty {
+        types.push(("return".to_string(), ty));
+    }
+    for arg in data.arg_data.iter() {
+        types.push((arg.debug_name.as_str().to_string(), arg.ty));
+    }
+    types.extend(gen_static_types(data));
+    resolve_typed_condition(condition, &types, data.tcx);
+}
+
+/// Resolves a condition against a list of typed variables, and type checks it.
+///
+/// # Arguments:
+/// * `condition` - The condition.
+/// * `types` - The name and type of each variable that may appear in the condition.
+/// * `tcx` - The type context, used to find constants and the fields of structs and enums.
+///
+/// # Remarks:
+/// * Constants are replaced with their values.
+/// * The struct fields named in the condition (ie: `p.x: u32`) are checked against the struct
+///   definitions, and array indexing and lengths (ie: `a[i]`, `len(a)`) are resolved against the
+///   array types.
+/// * Each enum predicate (ie: `x is Some(v)`) becomes a test of the enum's discriminant, and each
+///   name it binds becomes the variable holding the corresponding field of the variant.
+/// * The variables, fields, and array elements in the condition are typed by `types`, so their
+///   types may be left out (ie: `x` or `return`). A type that is given must match it.
+/// * The condition is type checked once it is resolved, when its integer literals are given
+///   their types.
+///
+pub fn resolve_typed_condition<'a, 'tcx>(condition: &mut Expression,
+                                         types: &[(String, Ty<'tcx>)],
+                                         tcx: TyCtxt<'a, 'tcx, 'tcx>) {
+    resolve_consts(condition, tcx);
+    resolve_paths(condition, types, tcx);
+
+    let mut bindings = Vec::new();
+    replace_variant_tests(condition, types, tcx, &mut bindings);
+
+    for (binding, field) in bindings {
+        // A bound name used inside old(..) still names the field it is bound to
+        let old_binding = gen_old_name(binding.as_str());
+        resolve_binding_type(condition, &binding, &field);
+        resolve_binding_type(condition, &old_binding, &field);
+        rename_variable(condition, binding.as_str(), field.name.as_str());
+        rename_variable(condition, old_binding.as_str(), field.name.as_str());
+    }
+
+    check_types_resolved(condition);
+    // Give the unsuffixed literals the types of their operands, then check the whole condition
+    let checked = match infer_literal_types(condition) {
+        Ok(_) => ty_check(condition),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = checked {
+        rp_error!("{}", e);
+    }
+}
+
+/// Finds the type of a variable that may appear in a condition.
+///
+/// # Arguments:
+/// * `name` - The name of the variable.
+/// * `types` - The name and type of each variable that may appear in the condition.
+///
+/// # Remarks:
+///
+pub fn find_ty<'tcx>(name: &str, types: &[(String, Ty<'tcx>)]) -> Option<Ty<'tcx>> {
+    types.iter().find(|&&(ref n, _)| n == name).map(|&(_, ty)| ty)
+}
+
+// Gives each use of a bound name the type of the field it is bound to, or checks that it has it
+fn resolve_binding_type(condition: &mut Expression, binding: &str, field: &VariableMappingData) {
+    match *condition {
+        Expression::BinaryExpression(ref mut b) => {
+            resolve_binding_type(&mut b.left, binding, field);
+            resolve_binding_type(&mut b.right, binding, field);
+        },
+        Expression::UnaryExpression(ref mut u) => {
+            resolve_binding_type(&mut u.e, binding, field);
+        },
+        Expression::Cast(ref mut c) => {
+            resolve_binding_type(&mut c.e, binding, field);
+        },
+        Expression::Select(ref mut s) => {
+            resolve_binding_type(&mut s.index, binding, field);
+        },
+        Expression::VariableMapping(ref mut v) => {
+            if v.name != binding {
+                return;
+            }
+            if v.var_type.is_empty() {
+                v.var_type = field.var_type.clone();
+            } else if v.var_type != field.var_type {
+                rp_error!("{} is bound to a field of type {}, but is used as {}",
+                          binding, field.var_type, v.var_type);
+            }
+        },
+        _ => {},
+    }
+}
+
+// Checks that every variable in a resolved condition has a type
+fn check_types_resolved(condition: &Expression) {
+    match *condition {
+        Expression::BinaryExpression(ref b) => {
+            check_types_resolved(&b.left);
+            check_types_resolved(&b.right);
+        },
+        Expression::UnaryExpression(ref u) => check_types_resolved(&u.e),
+        Expression::Cast(ref c) => check_types_resolved(&c.e),
+        Expression::Select(ref s) => {
+            check_types_resolved(&s.array);
+            check_types_resolved(&s.index);
+        },
+        Expression::VariableMapping(ref v) => {
+            if v.var_type.is_empty() {
+                rp_error!("The type of {} could not be found; give it explicitly (ie: {}: u32)",
+                          v.name, v.name);
+            }
+        },
+        _ => {},
+    }
+}
+
+// Resolves the paths in a condition against the types they name: checks that each field path
+// (ie: `return.inner.len`) names a field of a struct or tuple and has the type of that field, and
+// resolves array indexing and lengths (ie: `a[i]`, `len(a)`), following references to arrays
+fn resolve_paths<'a, 'tcx>(condition: &mut Expression,
+                           types: &[(String, Ty<'tcx>)],
+                           tcx: TyCtxt<'a, 'tcx, 'tcx>) {
+    let resolved = match *condition {
+        Expression::BinaryExpression(ref mut b) => {
+            resolve_paths(&mut b.left, types, tcx);
+            resolve_paths(&mut b.right, types, tcx);
+            return;
+        },
+        Expression::UnaryExpression(ref mut u) => {
+            resolve_paths(&mut u.e, types, tcx);
+            return;
+        },
+        Expression::Cast(ref mut c) => {
+            resolve_paths(&mut c.e, types, tcx);
+            return;
+        },
+        Expression::Select(ref mut s) => {
+            resolve_paths(&mut s.index, types, tcx);
+            let array = match *s.array {
+                Expression::VariableMapping(ref v) => v.clone(),
+                _ => rp_error!("Unsupported array in condition: {}", *s.array),
+            };
+            let old = strip_old_name(array.name.as_str()).map(|n| n.to_string());
+            let (name, ty) = gen_array_path(old.as_ref().unwrap_or(&array.name), types, tcx);
+            let element = array_element_type(ty.to_string().as_str());
+            if !array.var_type.is_empty() && element != array_element_type(&array.var_type) {
+                rp_error!("{} is an array of type {}, but is used as {}",
+                          array.name, ty, array.var_type);
+            }
+            *s.array = Expression::VariableMapping( VariableMappingData {
+                name: if old.is_some() { gen_old_name(name.as_str()) } else { name },
+                var_type: ty.to_string()
+            } );
+            return;
+        },
+        // old(x) is resolved as x, and then refers to the value on entry
+        Expression::VariableMapping(ref v) if strip_old_name(v.name.as_str()).is_some() => {
+            let mut path = Expression::VariableMapping( VariableMappingData {
+                name: strip_old_name(v.name.as_str()).unwrap().to_string(),
+                var_type: v.var_type.clone()
+            } );
+            resolve_paths(&mut path, types, tcx);
+            rename_to_old(&mut path);
+            Some(path)
+        },
+        Expression::VariableMapping(ref v) if v.name.contains('.') => {
+            // len(a) is a.len; a fixed-size array has a constant length
+            if v.name.ends_with(".len") && v.var_type == "usize" {
+                let base = &v.name[..v.name.len() - 4];
+                if let Some((name, ty)) = find_array_path(base, types, tcx) {
+                    match ty.sty {
+                        TypeVariants::TyArray(_, len) => Some(gen_usize(len as u64)),
+                        _ => Some(Expression::VariableMapping( VariableMappingData {
+                            name: name + ".len",
+                            var_type: "usize".to_string()
+                        } )),
+                    }
+                } else {
+                    resolve_path_type(v, types, tcx)
+                }
+            } else {
+                resolve_path_type(v, types, tcx)
+            }
+        },
+        // An argument, return, or static, or the value behind a reference (ie: `*x`)
+        Expression::VariableMapping(ref v) => {
+            if find_ty(v.name.trim_left_matches('*'), types).is_some() {
+                resolve_path_type(v, types, tcx)
+            } else {
+                None
+            }
+        },
+        _ => return,
+    };
+
+    if let Some(e) = resolved {
+        *condition = e;
+    }
+}
+
+// Finds the type named by a path (ie: `*p.x`) in a condition, if it is valid
+fn find_path_ty<'a, 'tcx>(path: &str,
+                          types: &[(String, Ty<'tcx>)],
+                          tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Result<Ty<'tcx>, String> {
+    // *p.x is the field x of the struct that p refers to
+    let deref = path.starts_with('*');
+    let mut fields = path.trim_left_matches('*').split('.');
+    let root = fields.next().unwrap();
+    let mut ty = match find_ty(root, types) {
+        Some(ty) => ty,
+        None => return Err(format!("Unknown variable in path: {}", path)),
+    };
+    if deref {
+        ty = match ty.sty {
+            TypeVariants::TyRef(_, mt) => mt.ty,
+            TypeVariants::TyBox(t) => t,
+            _ => return Err(format!("{} is not a reference: {}", root, path)),
+        };
+    }
+
+    for field in fields {
+        ty = match ty.sty {
+            TypeVariants::TyStruct(adt_def, substs) => {
+                match adt_def.struct_variant().fields.iter()
+                             .find(|f| &*f.name.as_str() == field) {
+                    Some(f) => f.ty(tcx, substs),
+                    None => return Err(format!("{} has no field named {}: {}", ty, field, path)),
+                }
+            },
+            TypeVariants::TyTuple(t) => {
+                match field.parse::<usize>().ok().and_then(|i| t.get(i)) {
+                    Some(t) => *t,
+                    None => return Err(format!("{} has no field {}: {}", ty, field, path)),
+                }
+            },
+            _ => return Err(format!("{} is not a struct or a tuple: {}", ty, path)),
+        };
+    }
+    Ok(ty)
+}
+
+// Checks that a path names a variable or field, and gives it the type of that variable or field.
+// A path given a type must already have it.
+fn resolve_path_type<'a, 'tcx>(v: &VariableMappingData,
+                               types: &[(String, Ty<'tcx>)],
+                               tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Option<Expression> {
+    let ty = match find_path_ty(v.name.as_str(), types, tcx) {
+        Ok(ty) => ty,
+        Err(e) => rp_error!("{}", e),
+    };
+    if v.var_type.is_empty() {
+        Some(Expression::VariableMapping( VariableMappingData {
+            name: v.name.clone(),
+            var_type: ty.to_string()
+        } ))
+    } else if ty.to_string() != v.var_type {
+        if v.name.contains('.') {
+            rp_error!("{} is a field of type {}, but is used as {}", v.name, ty, v.var_type);
+        } else {
+            rp_error!("{} has type {} in the signature, but is used as {}",
+                      v.name, ty, v.var_type);
+        }
+    } else {
+        None
+    }
+}
+
+// Finds the array or slice a path names, following a reference to it, if it names one
+fn find_array_path<'a, 'tcx>(path: &str,
+                             types: &[(String, Ty<'tcx>)],
+                             tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Option<(String, Ty<'tcx>)> {
+    let ty = match find_path_ty(path, types, tcx) {
+        Ok(ty) => ty,
+        Err(_) => return None,
+    };
+    let (name, ty) = match ty.sty {
+        TypeVariants::TyRef(_, mt) => (format!("*{}", path), mt.ty),
+        TypeVariants::TyBox(t) => (format!("*{}", path), t),
+        _ => (path.to_string(), ty),
+    };
+    match ty.sty {
+        TypeVariants::TyArray(..) | TypeVariants::TySlice(_) => Some((name, ty)),
+        _ => None,
+    }
+}
+
+// Finds the array or slice a path names, which must be one
+fn gen_array_path<'a, 'tcx>(path: &str,
+                            types: &[(String, Ty<'tcx>)],
+                            tcx: TyCtxt<'a, 'tcx, 'tcx>) -> (String, Ty<'tcx>) {
+    match find_array_path(path, types, tcx) {
+        Some(array) => array,
+        None => {
+            match find_path_ty(path, types, tcx) {
+                Ok(ty) => rp_error!("{} is not an array or a slice: {}", path, ty),
+                Err(e) => rp_error!("{}", e),
+            }
+        },
+    }
+}
@@ -0,0 +1,210 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to evaluate `const` items and promoted constants, and to resolve the constants named
+//! in conditions.
+
+use super::super::MirData;
+use super::{gen, gen_const_val, gen_deref_name};
+use std::process;
+use std::mem;
+use expression::*;
+use rustc::hir;
+use rustc::hir::def_id::DefId;
+use rustc::mir::repr::*;
+use rustc::ty::{TyCtxt, TypeVariants};
+use rustc::ty::subst::Substs;
+use rustc_const_eval::{lookup_const_by_id, eval_const_expr_partial, EvalHint};
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Returns the value of a `const` item used in MIR.
+///
+/// # Arguments:
+/// * `def_id` - The `DefId` of the item.
+/// * `substs` - The substitutions of the item's generic parameters.
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Return Value:
+/// * Returns the value as a literal Expression.
+///
+/// # Remarks:
+/// * The value is computed by rustc's const evaluator.
+///
+pub fn gen_item_const<'a, 'tcx>(def_id: DefId,
+                                substs: &'tcx Substs<'tcx>,
+                                data: &MirData<'a, 'tcx>)
+                                -> Expression {
+    let name = data.tcx.item_path_str(def_id);
+    match lookup_const_by_id(data.tcx, def_id, Some(substs)) {
+        Some((expr, _)) => {
+            match eval_const_expr_partial(data.tcx, expr, EvalHint::ExprTypeChecked, None) {
+                Ok(value) => gen_const_val(&value),
+                Err(err) => rp_error!("Unable to evaluate the constant {}: {}",
+                                      name, err.description()),
+            }
+        },
+        None => rp_error!("Unsupported constant: {}", name),
+    }
+}
+
+/// Returns the value of a promoted constant.
+///
+/// # Arguments:
+/// * `promoted` - The index of the promoted constant's MIR.
+/// * `deref` - Whether to return the value behind a promoted reference, rather than the
+///             reference itself.
+/// * `data` - Contains the promoted MIR of the function being analyzed.
+///
+/// # Return Value:
+/// * Returns the value as an Expression.
+///
+/// # Remarks:
+/// * Promoted MIR assigns the constant to its return value, so the value of the constant is the
+///   weakest precondition of the promoted MIR for the expression `return` (or `*return`).
+/// * A promoted reference itself is an unconstrained variable; only the value behind it is known.
+///
+pub fn gen_promoted(promoted: Promoted, deref: bool, data: &mut MirData) -> Expression {
+    let promoted_mir = data.promoted;
+    let mir_map = data.mir_map;
+    let mir = &promoted_mir[promoted];
+
+    // Give everything in the promoted MIR a fresh name
+    data.call_count += 1;
+    let prefix = format!("promoted{}!", data.call_count);
+    let mut constant = MirData::new(data.tcx, mir, mir_map, prefix.clone());
+    constant.overflow = data.overflow;
+    constant.pointer_width = data.pointer_width;
+
+    let mut result = VariableMappingData {
+        name: prefix + "return",
+        var_type: constant.func_return_type.clone()
+    };
+    if deref {
+        let referent = match constant.return_ty {
+            Some(ty) => {
+                match ty.sty {
+                    TypeVariants::TyRef(_, mt) => mt.ty.to_string(),
+                    _ => rp_error!("Promoted constant {:?} is not a reference", promoted),
+                }
+            },
+            None => rp_error!("Promoted constant {:?} has no value", promoted),
+        };
+        result = VariableMappingData {
+            name: gen_deref_name(result.name.as_str()),
+            var_type: referent
+        };
+    }
+
+    // The promoted MIR shares the checks and fresh names of the function
+    constant.checks = mem::replace(&mut data.checks, Vec::new());
+    constant.call_count = data.call_count;
+    let value = gen(0, &mut constant, &Some(Expression::VariableMapping(result)), false).unwrap();
+    data.checks = mem::replace(&mut constant.checks, Vec::new());
+    data.call_count = constant.call_count;
+
+    value
+}
+
+/// Returns whether an operand is a promoted reference.
+///
+/// # Arguments:
+/// * `operand` - The operand.
+///
+/// # Return Value:
+/// * Returns the index of the promoted constant if it is a reference, `None` otherwise.
+///
+/// # Remarks:
+///
+pub fn find_promoted_ref(operand: &Operand) -> Option<Promoted> {
+    if let Operand::Constant(ref c) = *operand {
+        if let Literal::Promoted{index} = c.literal {
+            if let TypeVariants::TyRef(..) = c.ty.sty {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+/// Replaces the `const` items named in a condition with their values.
+///
+/// # Arguments:
+/// * `condition` - The condition.
+/// * `tcx` - The type context, used to find and evaluate the `const` items of the crate.
+///
+/// # Remarks:
+/// * A constant is named by its path from the crate root (ie: `MAX_LEN` or `limits::MAX_LEN`),
+///   and its type must match the type it is given in the condition, if it is given one.
+/// * Arguments and locals cannot shadow constants in Rust, so a variable in a condition that
+///   names a constant always refers to that constant.
+///
+pub fn resolve_consts<'a, 'tcx>(condition: &mut Expression, tcx: TyCtxt<'a, 'tcx, 'tcx>) {
+    let value = match *condition {
+        Expression::BinaryExpression(ref mut b) => {
+            resolve_consts(&mut b.left, tcx);
+            resolve_consts(&mut b.right, tcx);
+            return;
+        },
+        Expression::UnaryExpression(ref mut u) => {
+            resolve_consts(&mut u.e, tcx);
+            return;
+        },
+        Expression::Cast(ref mut c) => {
+            resolve_consts(&mut c.e, tcx);
+            return;
+        },
+        Expression::Select(ref mut s) => {
+            resolve_consts(&mut s.index, tcx);
+            return;
+        },
+        Expression::VariableMapping(ref v) => {
+            // A constant has the same value on entry
+            let name = strip_old_name(v.name.as_str()).unwrap_or(v.name.as_str());
+            match find_const(name, tcx) {
+                Some(value) => {
+                    let ty = determine_evaluation_type(&value);
+                    if !v.var_type.is_empty() && ty != v.var_type {
+                        rp_error!("The constant {} has type {}, but is used as {}",
+                                  v.name, ty, v.var_type);
+                    }
+                    value
+                },
+                None => {
+                    if name.contains("::") {
+                        rp_error!("The constant {} was not found", name);
+                    }
+                    return;
+                },
+            }
+        },
+        _ => return,
+    };
+    *condition = value;
+}
+
+// Finds and evaluates the const item of the crate with the given path
+fn find_const<'a, 'tcx>(path: &str, tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Option<Expression> {
+    for (id, item) in tcx.map.krate().items.iter() {
+        if let hir::ItemConst(_, ref expr) = item.node {
+            if tcx.item_path_str(tcx.map.local_def_id(*id)) != path {
+                continue;
+            }
+            return match eval_const_expr_partial(tcx, expr, EvalHint::ExprTypeChecked, None) {
+                Ok(value) => Some(gen_const_val(&value)),
+                Err(err) => rp_error!("Unable to evaluate the constant {}: {}",
+                                      path, err.description()),
+            };
+        }
+    }
+    None
+}
@@ -0,0 +1,339 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to detect loops in the MIR control-flow graph and generate their weakest precondition.
+
+use super::super::MirData;
+use super::{gen_block, gen_lvalue, gen_check};
+use super::arrays::gen_index;
+use super::calls::gen_mutable_borrow;
+use super::conditions::resolve_typed_condition;
+use super::statics::{find_mutable_statics, gen_static_types};
+use std::process;
+use expression::*;
+use rustc::mir::repr::*;
+use rustc_data_structures::indexed_vec::Idx;
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Finds the loop headers of a function, which are the targets of back-edges in the MIR
+/// control-flow graph.
+///
+/// # Arguments:
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns the indices of the loop header `BasicBlock`s in source order.
+///
+/// # Remarks:
+/// * Unwinding edges are not followed; cleanup blocks never belong to a loop.
+/// * MIR does not number blocks in source order, so the headers are ordered by the start of the
+///   span of their terminator, which lies within the loop's condition or body.
+///
+pub fn find_loop_headers(data: &MirData) -> Vec<usize> {
+    let mut headers = Vec::new();
+    let mut visited = vec![false; data.block_data.len()];
+    let mut on_stack = vec![false; data.block_data.len()];
+
+    find_back_edges(0, data, &mut visited, &mut on_stack, &mut headers);
+
+    headers.sort();
+    headers.dedup();
+    headers.sort_by_key(|&header| data.block_data[header].terminator().source_info.span.lo);
+    headers
+}
+
+// Depth-first search of the control-flow graph; an edge to a block on the stack is a back-edge
+fn find_back_edges(index: usize,
+                   data: &MirData,
+                   visited: &mut Vec<bool>,
+                   on_stack: &mut Vec<bool>,
+                   headers: &mut Vec<usize>) {
+    visited[index] = true;
+    on_stack[index] = true;
+
+    for target in successors(index, data) {
+        if on_stack[target] {
+            headers.push(target);
+        } else if !visited[target] {
+            find_back_edges(target, data, visited, on_stack, headers);
+        }
+    }
+
+    on_stack[index] = false;
+}
+
+/// Returns the successors of a `BasicBlock` along the normal (non-unwinding) control flow.
+///
+/// # Arguments:
+/// * `index` - The index of the `BasicBlock` within MIR.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns the indices of the successor `BasicBlock`s.
+///
+/// # Remarks:
+///
+pub fn successors(index: usize, data: &MirData) -> Vec<usize> {
+    match data.block_data[index].terminator().kind {
+        TerminatorKind::Goto{target}
+        | TerminatorKind::Assert{target, ..}
+        | TerminatorKind::Drop{target, ..}
+        | TerminatorKind::DropAndReplace{target, ..} => vec![target.index()],
+        TerminatorKind::If{targets, ..} => vec![targets.0.index(), targets.1.index()],
+        TerminatorKind::Switch{ref targets, ..}
+        | TerminatorKind::SwitchInt{ref targets, ..} => {
+            targets.iter().map(|target| target.index()).collect()
+        },
+        TerminatorKind::Call{ref destination, ..} => {
+            match *destination {
+                Some((_, target)) => vec![target.index()],
+                None => Vec::new(),
+            }
+        },
+        TerminatorKind::Return
+        | TerminatorKind::Resume
+        | TerminatorKind::Unreachable => Vec::new(),
+    }
+}
+
+// Determines if the block at "to" can be reached from the block at "from"
+fn reaches(from: usize, to: usize, data: &MirData) -> bool {
+    let mut visited = vec![false; data.block_data.len()];
+    let mut stack = vec![from];
+
+    while let Some(index) = stack.pop() {
+        if index == to {
+            return true;
+        }
+        if !visited[index] {
+            visited[index] = true;
+            stack.extend(successors(index, data));
+        }
+    }
+    false
+}
+
+/// Returns all variables, temps, fields, and arrays assigned to within the body of a loop.
+///
+/// # Arguments:
+/// * `header` - The index of the loop header `BasicBlock`.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Remarks:
+/// * The body of the loop is every block that is both reachable from the header and can reach
+///   the header again.
+/// * A call modifies its destination, the places borrowed by the mutable references passed to
+///   it, and every `static mut` (see `calls::gen_call`).
+/// * A write to an element of a slice modifies the slice, but not its length `<slice>.len`,
+///   which is left out when the slice is renamed (see `gen_havoc`).
+///
+fn modified_variables(header: usize, data: &mut MirData) -> Vec<VariableMappingData> {
+    let mut vars: Vec<VariableMappingData> = Vec::new();
+
+    for index in 0..data.block_data.len() {
+        if !(reaches(header, index, data) && reaches(index, header, data)) {
+            continue;
+        }
+        let block = data.block_data[index];
+        for stmt in &block.statements {
+            match stmt.kind {
+                StatementKind::Assign(ref lvalue, _) => {
+                    // A write to an element modifies the whole array
+                    match gen_index(lvalue, data) {
+                        Some((array, _)) => vars.push(array),
+                        None => vars.push(gen_lvalue(lvalue.clone(), data)),
+                    }
+                },
+            }
+        }
+        match block.terminator().kind {
+            TerminatorKind::Call{ref args, ref destination, ..} => {
+                if let Some((ref lvalue, _)) = *destination {
+                    vars.push(gen_lvalue(lvalue.clone(), data));
+                }
+                // The callee, whether it has a contract or is inlined, may change the places
+                // borrowed by the mutable references passed to it, and every static mut
+                for operand in args {
+                    if let Some(place) = gen_mutable_borrow(operand, data) {
+                        vars.push(place);
+                    }
+                }
+                vars.extend(find_mutable_statics(data));
+            },
+            TerminatorKind::DropAndReplace{location: ref lvalue, ..} => {
+                vars.push(gen_lvalue(lvalue.clone(), data));
+            },
+            _ => {},
+        }
+    }
+
+    vars.sort_by(|a, b| a.name.cmp(&b.name));
+    vars.dedup();
+    vars
+}
+
+// Renames a variable modified by a loop, and its fields, to an arbitrary value. The length of a
+// slice never changes, so it keeps its name.
+fn gen_havoc(wp: &mut Expression, var: &VariableMappingData, havoc: &str) {
+    rename_variable(wp, var.name.as_str(), havoc);
+    if array_element_type(var.var_type.as_str()).is_some()
+       && array_length(var.var_type.as_str()).is_none() {
+        rename_variable(wp,
+                        format!("{}.len", havoc).as_str(),
+                        format!("{}.len", var.name).as_str());
+    }
+}
+
+/// Maps the names of local variables in a loop invariant onto the names they are given in MIR.
+///
+/// # Arguments:
+/// * `invariant` - The loop invariant, as parsed from the `invariant` attribute.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns the loop invariant with local variables renamed to `var<index>`, and constants
+///   replaced with their values.
+///
+/// # Remarks:
+/// * If several locals share a name (ie: shadowing), the first one declared is used.
+/// * Locals take precedence over function arguments of the same name.
+/// * Like a pre- or postcondition, the invariant is resolved against the types of the locals,
+///   arguments, and statics it names (see `resolve_typed_condition`).
+///
+pub fn gen_invariant(mut invariant: Expression, data: &MirData) -> Expression {
+    let mut types = Vec::new();
+    for var in data.var_data.iter() {
+        types.push((var.name.as_str().to_string(), var.ty));
+    }
+    for arg in data.arg_data.iter() {
+        types.push((arg.debug_name.as_str().to_string(), arg.ty));
+    }
+    types.extend(gen_static_types(data));
+    resolve_typed_condition(&mut invariant, &types, data.tcx);
+
+    for (index, var) in data.var_data.iter().enumerate() {
+        rename_variable(&mut invariant, &var.name.as_str(), format!("{}var{}", data.prefix, index).as_str());
+    }
+    invariant
+}
+
+/// Computes the weakest precondition of a loop from its invariant.
+///
+/// # Arguments:
+/// * `index` - The index of the loop header `BasicBlock` within MIR.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+/// * `post_expr` - The current weakest precondition (originally the postcondition) as an Expression.
+///
+/// # Return Value:
+/// * Returns the weakest precondition of the loop in the form of an Expression.
+///
+/// # Remarks:
+/// * wp(loop) => I AND ((I -> wp(header)) with the modified variables renamed). Renaming
+///   leaves the modified variables free in the verification condition, so `I -> wp(header)` must
+///   hold for any value they may take.
+/// * Within the body, a jump back to the header produces the invariant itself, so `wp(header)`
+///   holds only if the body preserves the invariant and the invariant implies the postcondition
+///   at every exit of the loop.
+///
+pub fn gen_loop(index: usize, data: &mut MirData, post_expr: &Option<Expression>, debug: bool)
+                -> Option<Expression> {
+    let invariant = match data.invariants.get(&index) {
+        Some(i) => i.clone(),
+        None => {
+            match data.unroll {
+                Some(bound) => return gen_unrolled_loop(index, bound, data, post_expr, debug),
+                None => rp_error!("No loop invariant was supplied for the loop at bb{}", index),
+            }
+        },
+    };
+
+    // The end of an iteration; the invariant must hold again
+    if data.active_loops.contains(&index) {
+        return Some(invariant);
+    }
+
+    data.active_loops.push(index);
+    let wp_header = gen_block(index, data, post_expr, debug);
+    data.active_loops.pop();
+
+    // The invariant is preserved, and implies the weakest precondition of each exit
+    let mut preserved = Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::Implication,
+        left: Box::new(invariant.clone()),
+        right: Box::new(wp_header.unwrap())
+    } );
+
+    // Rename everything modified by the loop to an arbitrary value
+    for var in modified_variables(index, data) {
+        let havoc = format!("{}!bb{}", var.name, index);
+        gen_havoc(&mut preserved, &var, havoc.as_str());
+    }
+
+    if debug {
+        println!("loop at bb{:?} preserves\t{:?}\n", index, preserved);
+    }
+
+    // The invariant holds on entry to the loop
+    Some(Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::And,
+        left: Box::new(invariant),
+        right: Box::new(preserved)
+    } ))
+}
+
+/// Computes the weakest precondition of a loop by unrolling it a bounded number of times.
+///
+/// # Arguments:
+/// * `index` - The index of the loop header `BasicBlock` within MIR.
+/// * `bound` - The maximum number of iterations of the loop.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+/// * `post_expr` - The current weakest precondition (originally the postcondition) as an Expression.
+///
+/// # Return Value:
+/// * Returns the weakest precondition of the unrolled loop in the form of an Expression.
+///
+/// # Remarks:
+/// * Each jump back to the header unrolls another iteration. Reaching the header once `bound`
+///   iterations have run produces the unwinding assertion, which is `false`: it holds only if
+///   the loop can never run longer than the bound.
+///
+fn gen_unrolled_loop(index: usize,
+                     bound: usize,
+                     data: &mut MirData,
+                     post_expr: &Option<Expression>,
+                     debug: bool)
+                     -> Option<Expression> {
+    let iterations = data.active_loops.iter().filter(|&&header| header == index).count();
+
+    // The unwinding assertion
+    if iterations > bound {
+        let label = format!("unwinding assertion: the loop at bb{} runs at most {} iteration(s)",
+                            index, bound);
+        return Some(gen_check(Expression::BooleanLiteral(false), label, data));
+    }
+
+    if debug {
+        println!("unrolling iteration {} of the loop at bb{:?}\n", iterations, index);
+    }
+
+    data.active_loops.push(index);
+    let wp = gen_block(index, data, post_expr, debug);
+    data.active_loops.pop();
+    wp
+}
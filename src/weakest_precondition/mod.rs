@@ -12,20 +12,36 @@
 
 extern crate rustc_const_math;
 
-use super::MirData;
+use super::{MirData, OverflowMode};
 use std::process;
 use expression::*;
 use rustc::mir::repr::*;
 use rustc::middle::const_val::ConstVal;
-use rustc_const_math::ConstInt;
+use rustc_const_math::{ConstInt, ConstIsize, ConstMathErr, Op};
 use rustc_data_structures::indexed_vec::Idx;
-use rustc::ty::{TypeVariants};
+use rustc::ty::{Ty, TypeVariants, AdtKind};
 
 use errors::{ColorConfig, Handler};
 use syntax::codemap::CodeMap;
 use std::rc::Rc;
 
 mod overflow;
+mod loops;
+mod adt;
+mod calls;
+mod arrays;
+mod consts;
+mod statics;
+mod chars;
+mod borrows;
+mod conditions;
+
+pub use self::loops::{find_loop_headers, gen_invariant};
+pub use self::conditions::resolve_condition;
+pub use self::statics::gen_static_values;
+pub use self::chars::gen_char_invariants;
+pub use self::adt::gen_discriminant_invariants;
+pub use self::borrows::find_borrows;
 
 /// Computes the weakest precondition for a given postcondition and a series of statements over one or more MIR basic blocks.
 ///
@@ -39,16 +55,71 @@ mod overflow;
 ///
 /// # Remarks:
 /// * This is the main generator for the weakest precondition, which evaluates the `BasicBlock`s recursively.
+/// * Loop headers are routed through `loops::gen_loop`, so that back-edges do not recurse forever.
 ///
 pub fn gen(index: usize, data: &mut MirData, post_expr: &Option<Expression>, debug: bool) -> Option<Expression> {
+    if data.loop_headers.contains(&index) {
+        loops::gen_loop(index, data, post_expr, debug)
+    } else {
+        gen_block(index, data, post_expr, debug)
+    }
+}
+
+/// Computes the weakest precondition of a single `BasicBlock`, following its terminator.
+///
+/// # Arguments:
+/// * `index` - The index of the `BasicBlock` within MIR.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from the MIR pass.
+/// * `post_expr` - The current weakest precondition (originally the postcondition) as an Expression.
+///
+/// # Return Value:
+/// * Returns the weakest precondition generated from the `BasicBlock` in the form of an Expression.
+///
+/// # Remarks:
+/// * Successor blocks are always evaluated through `gen`.
+///
+fn gen_block(index: usize, data: &mut MirData, post_expr: &Option<Expression>, debug: bool)
+             -> Option<Expression> {
     let mut wp: Option<Expression>;
 
     // Parse basic block terminator data
     let terminator = data.block_data[index].terminator.clone().unwrap().kind;
     match terminator {
-        // Assert{cond, expected, msg, target, cleanup}
-        TerminatorKind::Assert{target, ..}
-        | TerminatorKind::Goto{target} => {
+        // rustc's run-time checks (overflow, division by zero, bounds) are obligations, labeled
+        // with the kind of check
+        // wp(assert(c == e) x) => (c == e) AND x
+        TerminatorKind::Assert{cond, expected, msg, target, ..} => {
+            let wp_target = gen(target.index(), data, post_expr, debug);
+            let mut condition = gen_expression(&cond, data);
+            if !expected {
+                condition = Expression::UnaryExpression( UnaryExpressionData {
+                    op: UnaryOperator::Not,
+                    e: Box::new(condition)
+                } );
+            }
+            wp = match msg {
+                // The negation itself carries the overflow check, according to the overflow mode
+                // (see overflow::negation_check)
+                AssertMessage::Math(ConstMathErr::Overflow(Op::Neg)) => wp_target,
+                _ => {
+                    let label = match msg {
+                        AssertMessage::BoundsCheck{..} => {
+                            format!("index out of bounds at bb{}", index)
+                        },
+                        AssertMessage::Math(ref err) => {
+                            format!("{} at bb{}", err.description(), index)
+                        },
+                    };
+                    let check = gen_check(condition, label, data);
+                    Some(Expression::BinaryExpression( BinaryExpressionData {
+                        op: BinaryOperator::And,
+                        left: Box::new(check),
+                        right: Box::new(wp_target.unwrap())
+                    } ))
+                },
+            };
+        },
+        TerminatorKind::Goto{target} => {
             // Retrieve the weakest precondition from the following block
             wp = gen(target.index(), data, post_expr, debug);
         },
@@ -57,22 +128,39 @@ pub fn gen(index: usize, data: &mut MirData, post_expr: &Option<Expression>, deb
             return post_expr.clone();
         },
         // Call{func, args, destination, cleanup}
-        TerminatorKind::Call{func, ..} => {
+        TerminatorKind::Call{func, args, destination, ..} => {
             // Determine if this is the end of a panic. (assumed false branch of assertion, so
             // return a precondition of false [this path will never be taken])
-            match func {
+            let def_id = match func {
                 Operand::Constant (ref c) => {
                     let s = format!("{:?}", c.literal);
                     if s.contains("begin_panic") {
                         return Some(Expression::BooleanLiteral(false));
                     }
+                    match c.literal {
+                        Literal::Item {def_id, ..} => def_id,
+                        _ => rp_error!("Unsupported call at bb{}: {:?}", index, c.literal),
+                    }
                 },
                 // Consume (ref l)
-                Operand::Consume (..) => unimplemented!(),
+                Operand::Consume (..) => {
+                    rp_error!("Unsupported call at bb{}: calls through function pointers and \
+                              closures are not supported", index);
+                },
+            };
+            // Calls are verified against the callee's contract
+            let name = data.tcx.item_path_str(def_id);
+            wp = match calls::find_contract(def_id, data) {
+                Some(contract) => {
+                    calls::gen_call(index, name, contract, &args, &destination, data, post_expr,
+                                    debug)
+                },
+                // Calls without a contract are inlined
+                None => {
+                    calls::gen_inlined_call(index, def_id, name, &args, &destination, data,
+                                            post_expr, debug)
+                },
             };
-            // Due to the limited nature in which we handle Calls, we should never do anything
-            // other than return early or hit the unimplemented!() panic above.
-            unreachable!();
         },
         // Conditional statements
         // wp(if c x else y) => (c -> x) AND ((NOT c) -> y)
@@ -118,17 +206,116 @@ pub fn gen(index: usize, data: &mut MirData, post_expr: &Option<Expression>, deb
                 }))
             }));
         },
-        // Unimplemented TerminatorKinds
-        // DropAndReplace{location, value, target, unwind}
-        TerminatorKind::DropAndReplace{..} => unimplemented!(),
-        // Drop{location, target, unwind}
-        TerminatorKind::Drop{..} => unimplemented!(),
-        TerminatorKind::Unreachable => unimplemented!(),
-        TerminatorKind::Resume => unimplemented!(),
-        // Switch{discr, adt_def, targets}
-        TerminatorKind::Switch{..} => unimplemented!(),
-        // SwitchInt{discr, switch_ty, values, targets}
-        TerminatorKind::SwitchInt{..} => unimplemented!(),
+        // Dropping a value has no effect on the values that are modeled, and the unwind edge is
+        // not followed
+        // wp(drop(l) x) => x
+        TerminatorKind::Drop{target, ..} => {
+            wp = gen(target.index(), data, post_expr, debug);
+        },
+        // Replacing a value is an assignment, after the old value is dropped
+        // wp(replace(l, v) x) => x[l := v]
+        TerminatorKind::DropAndReplace{location, value, target, ..} => {
+            let wp_target = gen(target.index(), data, post_expr, debug);
+            let stmt = Statement {
+                source_info: data.block_data[index].terminator().source_info,
+                kind: StatementKind::Assign(location, Rvalue::Use(value))
+            };
+            wp = gen_stmt(wp_target.unwrap(), stmt, data, debug);
+        },
+        // Reaching an unreachable terminator is undefined behavior, so it is an obligation
+        // wp(unreachable) => false
+        TerminatorKind::Unreachable => {
+            let label = format!("unreachable code at bb{}", index);
+            wp = Some(gen_check(Expression::BooleanLiteral(false), label, data));
+        },
+        // Unwinding never returns normally, so the postcondition does not have to hold
+        TerminatorKind::Resume => {
+            return Some(Expression::BooleanLiteral(true));
+        },
+        // Switch statements on enums, with one target per variant
+        // wp(switch d {V_1 => x_1, .., V_n => x_n}) =>
+        //     ((d.discr == 1) -> x_1) AND .. AND ((d.discr == n) -> x_n)
+        TerminatorKind::Switch{discr, targets, ..} => {
+            let discriminant = Expression::VariableMapping(
+                adt::gen_discriminant(&gen_lvalue(discr, data))
+            );
+            // The conjunction of each branch
+            let mut wp_switch = Expression::BooleanLiteral(true);
+
+            for (variant, target) in targets.iter().enumerate() {
+                let wp_target = gen(target.index(), data, post_expr, debug);
+
+                // (d.discr == i) -> x_i
+                wp_switch = Expression::BinaryExpression(BinaryExpressionData {
+                    op: BinaryOperator::And,
+                    left: Box::new(wp_switch),
+                    right: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                        op: BinaryOperator::Implication,
+                        left: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                            op: BinaryOperator::Equal,
+                            left: Box::new(discriminant.clone()),
+                            right: Box::new(adt::gen_variant_index(variant))
+                        })),
+                        right: Box::new(wp_target.unwrap())
+                    }))
+                });
+            }
+            wp = Some(wp_switch);
+        },
+        // Switch statements on integers
+        // wp(switch d {v_1 => x_1, .., v_n => x_n, _ => y}) =>
+        //     ((d == v_1) -> x_1) AND .. AND ((d == v_n) -> x_n)
+        //     AND (((d != v_1) AND .. AND (d != v_n)) -> y)
+        TerminatorKind::SwitchInt{discr, values, targets, ..} => {
+            let discriminant = Expression::VariableMapping(gen_lvalue(discr, data));
+            // The condition of the otherwise branch
+            let mut otherwise = Expression::BooleanLiteral(true);
+            // The conjunction of each branch
+            let mut wp_switch = Expression::BooleanLiteral(true);
+
+            for (value, target) in values.iter().zip(targets.iter()) {
+                let value = gen_const_val(value);
+                let wp_target = gen(target.index(), data, post_expr, debug);
+
+                // (d == v_i) -> x_i
+                wp_switch = Expression::BinaryExpression(BinaryExpressionData {
+                    op: BinaryOperator::And,
+                    left: Box::new(wp_switch),
+                    right: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                        op: BinaryOperator::Implication,
+                        left: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                            op: BinaryOperator::Equal,
+                            left: Box::new(discriminant.clone()),
+                            right: Box::new(value.clone())
+                        })),
+                        right: Box::new(wp_target.unwrap())
+                    }))
+                });
+
+                // d != v_i
+                otherwise = Expression::BinaryExpression(BinaryExpressionData {
+                    op: BinaryOperator::And,
+                    left: Box::new(otherwise),
+                    right: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                        op: BinaryOperator::NotEqual,
+                        left: Box::new(discriminant.clone()),
+                        right: Box::new(value)
+                    }))
+                });
+            }
+
+            // The otherwise branch is always the last target
+            let wp_otherwise = gen(targets[values.len()].index(), data, post_expr, debug);
+            wp = Some(Expression::BinaryExpression(BinaryExpressionData {
+                op: BinaryOperator::And,
+                left: Box::new(wp_switch),
+                right: Box::new(Expression::BinaryExpression(BinaryExpressionData {
+                    op: BinaryOperator::Implication,
+                    left: Box::new(otherwise),
+                    right: Box::new(wp_otherwise.unwrap())
+                }))
+            }));
+        },
     }
 
     // Examine the statements in reverse order
@@ -154,6 +341,69 @@ pub fn gen(index: usize, data: &mut MirData, post_expr: &Option<Expression>, deb
     wp
 }
 
+/// Labels an obligation in the weakest precondition, so that it can be reported on individually.
+///
+/// # Arguments:
+/// * `obligation` - The Expression that must hold.
+/// * `label` - A description of the obligation, to be shown to the user.
+/// * `data` - Contains the `BasicBlockData` and all argument, temp, and variable declarations from
+///            the MIR pass.
+///
+/// # Return Value:
+/// * Returns `obligation OR check.<index>`, where `check.<index>` is replaced by `select_checks`.
+///
+/// # Remarks:
+/// * Obligations sharing a label share a check, and are enabled and disabled together.
+///
+fn gen_check(obligation: Expression, label: String, data: &mut MirData) -> Expression {
+    let index = match data.checks.iter().position(|l| *l == label) {
+        Some(index) => index,
+        None => {
+            data.checks.push(label);
+            data.checks.len() - 1
+        },
+    };
+
+    Expression::BinaryExpression( BinaryExpressionData {
+        op: BinaryOperator::Or,
+        left: Box::new(obligation),
+        right: Box::new(Expression::VariableMapping( VariableMappingData {
+            name: format!("check.{}", index),
+            var_type: "bool".to_string()
+        } ))
+    } )
+}
+
+/// Enables or disables the labeled checks in a verification condition.
+///
+/// # Arguments:
+/// * `vc` - The verification condition containing the checks.
+/// * `data` - Contains the labels of the checks generated from the MIR pass.
+/// * `selected` - The index of the only check to enable, or `None` to enable every check.
+///
+/// # Return Value:
+/// * Returns the verification condition, with each check either enabled or disabled.
+///
+/// # Remarks:
+/// * A disabled check always holds, so a verification condition with only one enabled check is
+///   invalid only if that check can fail.
+///
+pub fn select_checks(vc: &Expression, data: &MirData, selected: Option<usize>) -> Expression {
+    let mut vc = vc.clone();
+    for index in 0..data.checks.len() {
+        let enabled = match selected {
+            Some(s) => s == index,
+            None => true,
+        };
+        substitute_variable_with_expression(
+            &mut vc,
+            &VariableMappingData{ name: format!("check.{}", index), var_type: "bool".to_string() },
+            &Expression::BooleanLiteral(!enabled)
+        );
+    }
+    vc
+}
+
 /// Returns the type of an operand as a `String` (ie: `"i32"`, `"bool"`, etc.)
 ///
 /// # Arguments:
@@ -180,12 +430,149 @@ fn gen_ty(operand: &Operand, data: &mut MirData) -> String {
                 Lvalue::Var(ref var) => {
                     data.var_data[var.index()].ty.to_string()
                 },
-                _ => unimplemented!(),
+                // Fields, and values behind references
+                _ => gen_lvalue(lvalue.clone(), data).var_type,
             }
         }
     }
 }
 
+/// Substitutes the overflow flag of a checked binary operation into wp.
+///
+/// # Arguments:
+/// * `wp` - The current weakest precondition.
+/// * `flag` - The overflow flag.
+/// * `var` - The variable the result of the operation is assigned to.
+/// * `binop` - The operation.
+/// * `lvalue` - The left-hand operand.
+/// * `rvalue` - The right-hand operand.
+/// * `mode` - How overflow is treated.
+/// * `pointer_width` - The width of `usize` and `isize`.
+///
+/// # Return Value:
+/// * Returns the modified weakest precondition.
+///
+/// # Remarks:
+/// * rustc asserts that the flag is false, so in `Panic` mode the overflow check is reported with
+///   the label of that assert. In the other modes the flag is false, and in `Ignore` mode the
+///   operation is also assumed not to overflow.
+///
+fn add_overflow_flag(wp: &Expression,
+                     flag: &VariableMappingData,
+                     var: &VariableMappingData,
+                     binop: &BinOp,
+                     lvalue: &Expression,
+                     rvalue: &Expression,
+                     mode: OverflowMode,
+                     pointer_width: u8)
+                     -> Expression {
+    let no_overflow = overflow::no_overflow(var, binop, lvalue, rvalue, pointer_width);
+    let overflow = match mode {
+        OverflowMode::Panic => {
+            Expression::UnaryExpression( UnaryExpressionData {
+                op: UnaryOperator::Not,
+                e: Box::new(no_overflow.clone())
+            } )
+        },
+        OverflowMode::Wrap | OverflowMode::Ignore => Expression::BooleanLiteral(false),
+    };
+    let mut wp = wp.clone();
+    substitute_variable_with_expression(&mut wp, flag, &overflow);
+    match mode {
+        OverflowMode::Ignore => overflow::apply_overflow_mode(&wp, &no_overflow, mode),
+        _ => wp,
+    }
+}
+
+/// Returns the overflow flag of a checked shift.
+///
+/// # Arguments:
+/// * `lvalue` - The value being shifted.
+/// * `rvalue` - The shift amount.
+/// * `pointer_width` - The width of `usize` and `isize`.
+///
+/// # Remarks:
+/// * rustc asserts that the flag is false, so the shift amount check is reported with the label
+///   of that assert.
+///
+fn gen_shift_flag(lvalue: &Expression, rvalue: &Expression, pointer_width: u8) -> Expression {
+    Expression::UnaryExpression( UnaryExpressionData {
+        op: UnaryOperator::Not,
+        e: Box::new(gen_shift_check(lvalue, rvalue, pointer_width))
+    } )
+}
+
+/// Generates a version of wp "And"ed together with a conditional expression that mimics a check
+/// to ensure a shift amount is less than the width of the value being shifted.
+///
+/// # Arguments:
+/// * `wp` - The current weakest precondition that the shift amount check is to be "And"ed to
+/// * `lvalue` - The value being shifted
+/// * `rvalue` - The shift amount
+/// * `pointer_width` - The width of `usize` and `isize`
+///
+/// # Return Value:
+/// * Returns the modified weakest precondition with the shift amount check "And"ed
+///
+/// # Remarks:
+/// * The shift amount may have a different integer type than the value being shifted.
+///
+fn add_shift_check(wp: &Expression, lvalue: &Expression, rvalue: &Expression, pointer_width: u8)
+                   -> Expression {
+    Expression::BinaryExpression( BinaryExpressionData{
+        // And weakest precondtion and shift amount check
+        op: BinaryOperator::And,
+        left: Box::new(wp.clone()),
+        right: Box::new(gen_shift_check(lvalue, rvalue, pointer_width))
+    })
+}
+
+// The shift amount is less than the width of the value being shifted, and is not negative
+fn gen_shift_check(lvalue: &Expression, rvalue: &Expression, pointer_width: u8) -> Expression {
+    let l_type = determine_evaluation_type(lvalue);
+    let r_type = determine_evaluation_type(rvalue);
+    let width = match type_size(l_type.as_str(), pointer_width) {
+        Some(size) => size,
+        None => rp_error!("Unimplemented shift of type {}", l_type),
+    };
+    // The width, as a value of the shift amount's type
+    let r_size = match type_size(r_type.as_str(), pointer_width) {
+        Some(size) => size,
+        None => rp_error!("Unimplemented shift amount of type {}", r_type),
+    };
+    let (zero, limit) = if r_type == "usize" {
+        (arrays::gen_usize(0), arrays::gen_usize(width as u64))
+    } else if r_type == "isize" {
+        (gen_isize(0), gen_isize(width as i64))
+    } else if r_type.starts_with('i') {
+        (Expression::SignedBitVector( SignedBitVectorData { size: r_size, value: 0 } ),
+         Expression::SignedBitVector( SignedBitVectorData { size: r_size, value: width as i64 } ))
+    } else {
+        (Expression::UnsignedBitVector( UnsignedBitVectorData { size: r_size, value: 0 } ),
+         Expression::UnsignedBitVector( UnsignedBitVectorData { size: r_size, value: width as u64 } ))
+    };
+
+    // r < width
+    let below_width = Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::LessThan,
+        left: Box::new(rvalue.clone()),
+        right: Box::new(limit)
+    });
+    if !r_type.starts_with('i') {
+        return below_width;
+    }
+    // 0 <= r && r < width
+    Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::And,
+        left: Box::new(Expression::BinaryExpression( BinaryExpressionData{
+            op: BinaryOperator::GreaterThanOrEqual,
+            left: Box::new(rvalue.clone()),
+            right: Box::new(zero)
+        })),
+        right: Box::new(below_width)
+    })
+}
+
 /// Generates a version of wp "And"ed together with a conditional expression that mimics a check
 /// to ensure division by 0 does not occur.
 ///
@@ -200,6 +587,23 @@ fn gen_ty(operand: &Operand, data: &mut MirData) -> String {
 /// * Currently supported `ConstInt`: `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
 fn add_zero_check(wp: &Expression, exp: &Expression) -> Expression {
+    // Pointer-sized zeros are written like other pointer-sized constants
+    let zero = match determine_evaluation_type(exp).as_str() {
+        "usize" => Some(arrays::gen_usize(0)),
+        "isize" => Some(gen_isize(0)),
+        _ => None,
+    };
+    if let Some(zero) = zero {
+        return Expression::BinaryExpression( BinaryExpressionData{
+            op: BinaryOperator::And,
+            left: Box::new(wp.clone()),
+            right: Box::new(Expression::BinaryExpression( BinaryExpressionData{
+                op: BinaryOperator::NotEqual,
+                left: Box::new(exp.clone()),
+                right: Box::new(zero)
+            }))
+        });
+    }
 
     if determine_evaluation_type(exp).starts_with('i') {
         Expression::BinaryExpression( BinaryExpressionData{
@@ -282,35 +686,56 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
         },
         //_ => return Some(wp)
     }
+    // An element of an array on the left-hand side is stored into the array
+    let lvalue = lvalue.unwrap();
+    let store = arrays::gen_index(&lvalue, data);
+
     // The variable or temp on the left-hand side of the assignment
-    let mut var = gen_lvalue(lvalue.unwrap(), data);
+    let mut var = gen_lvalue(lvalue, data);
+    // How arithmetic overflow is treated
+    let mode = data.overflow;
+    // The width of usize and isize
+    let width = data.pointer_width;
 
     // The expression on the right-hand side of the assignment
     let mut expression = Vec::new();
+    // The fresh array and the value of its elements, for an array of repeated elements
+    let mut repeat = None;
     match rvalue.clone().unwrap() {
+        // The result is the field .0, and the overflow flag, which rustc asserts is false, is the
+        // field .1
+        // wp(x = checked(a op b)) => wp[x.0 := a op b][x.1 := NOT no_overflow(a op b)]
         Rvalue::CheckedBinaryOp(ref binop, ref loperand, ref roperand) => {
             let lvalue: Expression = gen_expression(loperand, data);
             let rvalue: Expression = gen_expression(roperand, data);
+            let flag = VariableMappingData {
+                name: var.name.clone() + ".1",
+                var_type: "bool".to_string()
+            };
             let op: BinaryOperator = match *binop {
                 BinOp::Add => {
-                    // Add the overflow expression checks
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    // The overflow flag
+                    wp = add_overflow_flag(&wp, &flag, &var, binop, &lvalue, &rvalue, mode,
+                                           width);
                     BinaryOperator::Addition
                 },
                 BinOp::Sub => {
-                    // Add the overflow and underflow expression checks
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    // The overflow and underflow flag
+                    wp = add_overflow_flag(&wp, &flag, &var, binop, &lvalue, &rvalue, mode,
+                                           width);
                     BinaryOperator::Subtraction
                 },
                 BinOp::Mul => {
-                    // Add the overflow and underflow expression checks
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    // The overflow and underflow flag
+                    wp = add_overflow_flag(&wp, &flag, &var, binop, &lvalue, &rvalue, mode,
+                                           width);
                     BinaryOperator::Multiplication
                 },
                 BinOp::Div => {
                     // Add the overflow and underflow expression checks, if operands are signed
                     if determine_evaluation_type(&rvalue).starts_with('i') {
-                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue,
+                                                      OverflowMode::Panic, width);
                     }
                     // Add the division by 0 expression check
                     wp = add_zero_check(&wp, &rvalue);
@@ -319,14 +744,24 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
                 BinOp::Rem => {
                 	// Add the overflow and underflow expression checks, if operands are signed
                     if determine_evaluation_type(&rvalue).starts_with('i') {
-                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue,
+                                                      OverflowMode::Panic, width);
                     }
                     // Add the division by 0 expression check
                     wp = add_zero_check(&wp, &rvalue);
                     BinaryOperator::Modulo
                 },
-                BinOp::Shl => BinaryOperator::BitwiseLeftShift,
-                BinOp::Shr => BinaryOperator::BitwiseRightShift,
+                // The shift amount overflows if it is not less than the width of the value
+                BinOp::Shl => {
+                    let overflow = gen_shift_flag(&lvalue, &rvalue, width);
+                    substitute_variable_with_expression(&mut wp, &flag, &overflow);
+                    BinaryOperator::BitwiseLeftShift
+                },
+                BinOp::Shr => {
+                    let overflow = gen_shift_flag(&lvalue, &rvalue, width);
+                    substitute_variable_with_expression(&mut wp, &flag, &overflow);
+                    BinaryOperator::BitwiseRightShift
+                },
                 _ => rp_error!("Unsupported checked binary operation!"),
             };
 
@@ -346,42 +781,38 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
             let op: BinaryOperator = match *binop {
                 BinOp::Add => {
                     // Add the overflow expression check
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue, mode,
+                                                  width);
                     BinaryOperator::Addition
                 },
                 BinOp::Sub => {
                     // Add the overflow and underflow expression checks
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue, mode,
+                                                  width);
                     BinaryOperator::Subtraction
                 },
                 BinOp::Mul => {
                     // Add the overflow and underflow expression checks
-                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
+                    wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue, mode,
+                                                  width);
                     BinaryOperator::Multiplication
                 },
-                BinOp::Div => {
-                    // Add the overflow and underflow expression checks, if operands are signed
-                    if determine_evaluation_type(&rvalue).starts_with('i') {
-                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
-                    }
-                    // Add the division by 0 expression check
-                    wp = add_zero_check(&wp, &rvalue);
-                    BinaryOperator::Division
-                },
-                BinOp::Rem => {
-                	// Add the overflow and underflow expression checks, if operands are signed
-                    if determine_evaluation_type(&rvalue).starts_with('i') {
-                        wp = overflow::overflow_check(&wp, &var, binop, &lvalue, &rvalue);
-                    }
-                    // Add the division by 0 expression check
-                    wp = add_zero_check(&wp, &rvalue);
-                    BinaryOperator::Modulo
-                },
+                // Division by zero, and signed overflow, are checked by rustc's asserts
+                BinOp::Div => BinaryOperator::Division,
+                BinOp::Rem => BinaryOperator::Modulo,
                 BinOp::BitOr => BinaryOperator::BitwiseOr,
                 BinOp::BitAnd => BinaryOperator::BitwiseAnd,
                 BinOp::BitXor => BinaryOperator::BitwiseXor,
-                BinOp::Shl => BinaryOperator::BitwiseLeftShift,
-                BinOp::Shr => BinaryOperator::BitwiseRightShift,
+                BinOp::Shl => {
+                    // Add the shift amount check
+                    wp = add_shift_check(&wp, &lvalue, &rvalue, width);
+                    BinaryOperator::BitwiseLeftShift
+                },
+                BinOp::Shr => {
+                    // Add the shift amount check
+                    wp = add_shift_check(&wp, &lvalue, &rvalue, width);
+                    BinaryOperator::BitwiseRightShift
+                },
                 BinOp::Lt => BinaryOperator::LessThan,
                 BinOp::Le => BinaryOperator::LessThanOrEqual,
                 BinOp::Gt => BinaryOperator::GreaterThan,
@@ -407,7 +838,11 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
                         UnaryOperator::BitwiseNot
                     }
                 },
-                UnOp::Neg => UnaryOperator::Negation,
+                UnOp::Neg => {
+                    // Add the overflow check
+                    wp = overflow::negation_check(&wp, &var, &exp, mode, width);
+                    UnaryOperator::Negation
+                },
             };
             // push the ne new exp onto the expression: Vec<>
             expression.push(Expression::UnaryExpression( UnaryExpressionData {
@@ -417,6 +852,27 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
         },
         //  FIXME: need def
         Rvalue::Use(ref operand) => {
+            // Moving an enum also moves its discriminant and fields, and moving a reference
+            // moves the value behind it. An element of an array is read through the array
+            // instead (see gen_expression).
+            if let Operand::Consume(ref l) = *operand {
+                if arrays::gen_index(l, data).is_none() {
+                    let source = gen_lvalue(l.clone(), data);
+                    rename_variable(&mut wp, var.name.as_str(), source.name.as_str());
+                    rename_variable(&mut wp,
+                                    gen_deref_name(var.name.as_str()).as_str(),
+                                    gen_deref_name(source.name.as_str()).as_str());
+                }
+            }
+            // The value behind a promoted reference is the promoted constant
+            if let Some(index) = consts::find_promoted_ref(operand) {
+                let referent = VariableMappingData {
+                    name: gen_deref_name(var.name.as_str()),
+                    var_type: gen_pointee_ty(&var.var_type)
+                };
+                let value = consts::gen_promoted(index, true, data);
+                substitute_variable_with_expression(&mut wp, &referent, &value);
+            }
             expression.push(gen_expression(operand, data));
         },
         //  FIXME: need def
@@ -432,28 +888,121 @@ fn gen_stmt(mut wp: Expression, stmt: Statement, data: &mut MirData, debug: bool
                         expression.push(e);
                     }
                 },
-                _ => rp_error!("Unsupported aggregate: only tuples are supported"),
+                // wp(x = V_i(a_1, .., a_n)) =>
+                //     wp[x.discr := i][x.V_i.f_1 := a_1]..[x.V_i.f_n := a_n]
+                AggregateKind::Adt(adt_def, variant, substs)
+                    if adt_def.adt_kind() == AdtKind::Enum => {
+                    let variant_def = &adt_def.variants[variant];
+                    substitute_variable_with_expression(
+                        &mut wp,
+                        &adt::gen_discriminant(&var),
+                        &adt::gen_variant_index(variant)
+                    );
+                    for (operand, field) in vec_operand.iter().zip(variant_def.fields.iter()) {
+                        let field_var = VariableMappingData {
+                            name: adt::gen_field_name(var.name.as_str(),
+                                                      &variant_def.name.as_str(),
+                                                      &field.name.as_str()),
+                            var_type: field.ty(data.tcx, substs).to_string()
+                        };
+                        let e = gen_expression(operand, data);
+                        substitute_variable_with_expression(&mut wp, &field_var, &e);
+                    }
+                },
+                // wp(x = S { f_1: a_1, .., f_n: a_n }) => wp[x.f_1 := a_1]..[x.f_n := a_n]
+                AggregateKind::Adt(adt_def, _, substs) if adt_def.adt_kind() == AdtKind::Struct => {
+                    let variant_def = adt_def.struct_variant();
+                    for (operand, field) in vec_operand.iter().zip(variant_def.fields.iter()) {
+                        let field_var = VariableMappingData {
+                            name: var.name.clone() + "." + &field.name.as_str(),
+                            var_type: field.ty(data.tcx, substs).to_string()
+                        };
+                        // A field that is itself a struct, an enum or a reference takes its
+                        // fields, or the value behind it, from the operand
+                        if let Operand::Consume(ref l) = *operand {
+                            let source = gen_lvalue(l.clone(), data);
+                            rename_variable(&mut wp, field_var.name.as_str(), source.name.as_str());
+                            rename_variable(&mut wp,
+                                            gen_deref_name(field_var.name.as_str()).as_str(),
+                                            gen_deref_name(source.name.as_str()).as_str());
+                        }
+                        let e = gen_expression(operand, data);
+                        substitute_variable_with_expression(&mut wp, &field_var, &e);
+                    }
+                },
+                // wp(x = [a_0, .., a_n]) => wp[x := store(..store(x, 0, a_0).., n, a_n)]
+                AggregateKind::Vec => {
+                    let mut e = Expression::VariableMapping(var.clone());
+                    for (i, operand) in vec_operand.iter().enumerate() {
+                        e = Expression::Store( StoreData {
+                            array: Box::new(e),
+                            index: Box::new(arrays::gen_usize(i as u64)),
+                            value: Box::new(gen_expression(operand, data))
+                        } );
+                    }
+                    expression.push(e);
+                },
+                _ => rp_error!("Unsupported aggregate: only tuples, arrays, structs and enums are \
+                               supported"),
             }
         },
-        // FIXME: need def
-        // Cast(ref cast_kind, ref cast_operand, ref cast_ty)
-        Rvalue::Cast(..) => {
-            expression.push(Expression::VariableMapping(var.clone()));
+        // Numeric casts; the operand is converted to the target type
+        Rvalue::Cast(ref cast_kind, ref cast_operand, ref cast_ty) => {
+            match *cast_kind {
+                CastKind::Misc => {
+                    expression.push(Expression::Cast( CastData {
+                        e: Box::new(gen_expression(cast_operand, data)),
+                        ty: cast_ty.to_string()
+                    } ));
+                },
+                _ => rp_error!("Unsupported cast: only numeric casts are supported"),
+            }
         },
-        // FIXME: need def
-        // Ref(ref ref_region, ref ref_borrow_kind, ref ref_lvalue) => {
-        Rvalue::Ref(..) => {
-            expression.push(Expression::VariableMapping(var.clone()));
+        // Borrows. A reference temp or variable stands for the place it borrows, so *r never
+        // appears in the weakest precondition; any other reference has the borrowed place as the
+        // value behind it.
+        // wp(r = &y) => wp[*r := y]
+        Rvalue::Ref(_, _, ref ref_lvalue) => {
+            let target = gen_lvalue(ref_lvalue.clone(), data);
+            rename_variable(&mut wp,
+                            gen_deref_name(var.name.as_str()).as_str(),
+                            target.name.as_str());
+        },
+        // The length of an array or slice
+        Rvalue::Len(ref len_lvalue) => {
+            let array = gen_lvalue(len_lvalue.clone(), data);
+            expression.push(arrays::gen_len(&array));
+        },
+        // The new array is a fresh variable, whose elements are constrained once it is
+        // substituted (see arrays::gen_repeat)
+        // wp(x = [a; n]) => (forall k. x'[k] == a) -> wp[x := x']
+        Rvalue::Repeat(ref operand, _) => {
+            data.call_count += 1;
+            let array = VariableMappingData {
+                name: format!("{}!repeat{}", var.name, data.call_count),
+                var_type: var.var_type.clone()
+            };
+            expression.push(Expression::VariableMapping(array.clone()));
+            repeat = Some((array, gen_expression(operand, data)));
         },
         // Unimplemented Rvalues
         Rvalue::Box(..) => unimplemented!(),
-        Rvalue::Len(..) => unimplemented!(),
         _ => unimplemented!(),
     };
 
     // Replace any appearance of var in the weakest precondition with the expression
     for expr in &expression {
-        substitute_variable_with_expression( &mut wp, &var, expr );
+        match store {
+            // wp(a[i] = x) => wp[a := store(a, i, x)]
+            Some((ref array, ref index)) => {
+                let e = arrays::gen_store(array, index, expr);
+                substitute_variable_with_expression( &mut wp, array, &e );
+            },
+            None => substitute_variable_with_expression( &mut wp, &var, expr ),
+        }
+    }
+    if let Some((array, value)) = repeat {
+        wp = arrays::gen_repeat(&wp, &array, &value);
     }
     // Prints the new weakest precondition
     if debug {
@@ -480,7 +1029,7 @@ fn gen_lvalue(lvalue: Lvalue, data: &mut MirData) -> VariableMappingData {
         Lvalue::Arg(ref arg) => {
             // Find the name and type in the declaration
             VariableMappingData{
-                name: data.arg_data[arg.index()].debug_name.as_str().to_string(),
+                name: data.prefix.clone() + &data.arg_data[arg.index()].debug_name.as_str(),
                 var_type: data.arg_data[arg.index()].ty.clone().to_string()
             }
         },
@@ -494,7 +1043,7 @@ fn gen_lvalue(lvalue: Lvalue, data: &mut MirData) -> VariableMappingData {
                 }
             }
             VariableMappingData{
-                name: "tmp".to_string() + temp.index().to_string().as_str(),
+                name: data.prefix.clone() + "tmp" + temp.index().to_string().as_str(),
                 var_type: ty
             }
         },
@@ -502,88 +1051,197 @@ fn gen_lvalue(lvalue: Lvalue, data: &mut MirData) -> VariableMappingData {
         Lvalue::Var(ref var) => {
             // Find the name and type in the declaration
             VariableMappingData{
-                name: "var".to_string() + var.index().to_string().as_str(),
+                name: data.prefix.clone() + "var" + var.index().to_string().as_str(),
                 var_type: data.var_data[var.index()].ty.clone().to_string()
             }
         },
         // The returned value
         Lvalue::ReturnPointer => {
             VariableMappingData{
-                name: "return".to_string(),
+                name: data.prefix.clone() + "return",
                 var_type: data.func_return_type.clone()
             }
         },
-        // (Most likely) a field of a tuple from a checked operation
+        // A field, or the value behind a reference
         Lvalue::Projection(pro) => {
+            arrays::check_element_base(&pro.as_ref().base);
+            // The value behind a reference: the borrowed place, if the reference is a temp or
+            // variable (see borrows::find_borrows), or otherwise a variable named *<base>
+            if let ProjectionElem::Deref = pro.as_ref().elem {
+                let base = gen_lvalue(pro.as_ref().base.clone(), data);
+                if let Some(place) = data.borrows.get(&base.name).cloned() {
+                    return gen_lvalue(place, data);
+                }
+                return VariableMappingData{
+                    name: gen_deref_name(base.name.as_str()),
+                    var_type: gen_pointee_ty(base.var_type.as_str())
+                };
+            }
+            // A field of an enum variant, named <base>.<variant>.<field>
+            if let Lvalue::Projection(ref downcast) = pro.as_ref().base {
+                if let ProjectionElem::Downcast(adt_def, variant) = downcast.elem {
+                    if let ProjectionElem::Field(ref field, ref ty) = pro.as_ref().elem {
+                        let base = gen_lvalue(downcast.base.clone(), data);
+                        let variant_def = &adt_def.variants[variant];
+                        return VariableMappingData{
+                            name: adt::gen_field_name(base.name.as_str(),
+                                                      &variant_def.name.as_str(),
+                                                      &variant_def.fields[field.index()].name.as_str()),
+                            var_type: ty.to_string()
+                        };
+                    }
+                }
+            }
 
-            // Get the index
-            let index: String = match pro.as_ref().elem.clone() {
-                // Index(ref o)
-                ProjectionElem::Index(_) => unimplemented!(),
-                // Field(ref field, ref ty)
-                ProjectionElem::Field(ref field, _) => { (field.index() as i32).to_string() }
-                _ => unimplemented!(),
-            };
-
-            // Get the name of the variable being projected
-            let lvalue_name;
-            let lvalue_type;
-
-            match pro.as_ref().base {
-                // Argument
-                Lvalue::Arg(ref arg) => {
-                    // Return the name of the argument
-                    lvalue_name = data.arg_data[arg.index()].debug_name.as_str().to_string();
-                    lvalue_type = data.arg_data[arg.index()].ty.clone().to_string();
-                },
-                // Temporary variable
-                Lvalue::Temp(ref temp) => {
-                    // Return "temp<index>"
-                    lvalue_name = "tmp".to_string() + temp.index().to_string().as_str();
-                    // warning: value assigned to `lvalue_type` is never read
-                    // lvalue_type = data.temp_data[temp.index()].ty.clone().to_string();
-                    match data.temp_data[temp.index()].ty.sty {
-                        TypeVariants::TyTuple(t) => { lvalue_type = t[0].to_string(); },
-                        _ => unimplemented!(),
+            match pro.as_ref().elem.clone() {
+                // A field of a struct or tuple, named <base>.<field>. The fields of a tuple from
+                // a checked operation are <base>.0 (the result) and <base>.1 (the overflow flag).
+                ProjectionElem::Field(ref field, ref ty) => {
+                    let base = gen_lvalue(pro.as_ref().base.clone(), data);
+                    let field_name = gen_field_label(&pro.as_ref().base, field.index(), data);
+                    VariableMappingData{
+                        name: base.name + "." + field_name.as_str(),
+                        var_type: ty.to_string()
                     }
                 },
-                // Local variable
-                Lvalue::Var(ref var) => {
-                    // Return the name of the variable
-                    let i = index.parse::<usize>().unwrap();
-                    lvalue_name = "var".to_string() + var.index().to_string().as_str();
-                    match data.var_data[var.index()].ty.sty {
-                        TypeVariants::TyTuple(t) => {
-                            lvalue_type = t[i].to_string();
-                        },
-                        _ => unimplemented!(),
+                // An element of an array, named <base>[]; elements are read and written through
+                // the array itself (see arrays::gen_index)
+                ProjectionElem::Index(_) => {
+                    let base = gen_lvalue(pro.as_ref().base.clone(), data);
+                    VariableMappingData{
+                        name: base.name.clone() + "[]",
+                        var_type: array_element_type(base.var_type.as_str())
+                                      .unwrap_or(base.var_type)
                     }
                 },
-                // Unimplemented Lvalue
-                Lvalue::ReturnPointer => unimplemented!(),
-                // Static(ref stat)
-                Lvalue::Static(_) => unimplemented!(),
-                // Multiply-nested projection
-                Lvalue::Projection(_) => unimplemented!(),
-            };
+                _ => unimplemented!(),
+            }
+        },
+        // A static, which is a global variable named by its path
+        Lvalue::Static(def_id) => statics::gen_static(def_id, data),
+    }
+}
 
-            // Get the index
-            let index: String = match pro.as_ref().elem.clone() {
 
-                // Field(ref field, ref ty)
-                ProjectionElem::Field(ref field, _) => (field.index() as i32).to_string(),
-                // Index(ref o)
-                ProjectionElem::Index(_) => unimplemented!(),
-                _ => unimplemented!(),
-            };
+// Finds the name of a field of the struct or tuple an lvalue refers to
+fn gen_field_label(base: &Lvalue, field: usize, data: &MirData) -> String {
+    // The number of references and arrays between the base and the type it is projected from
+    let mut derefs = 0;
+    let mut lvalue = base;
+    loop {
+        let ty = match *lvalue {
+            Lvalue::Arg(ref arg) => data.arg_data[arg.index()].ty,
+            Lvalue::Temp(ref temp) => data.temp_data[temp.index()].ty,
+            Lvalue::Var(ref var) => data.var_data[var.index()].ty,
+            Lvalue::ReturnPointer => {
+                match data.return_ty {
+                    Some(ty) => ty,
+                    None => return field.to_string(),
+                }
+            },
+            Lvalue::Projection(ref pro) => {
+                match pro.elem {
+                    ProjectionElem::Deref | ProjectionElem::Index(_) => {
+                        derefs += 1;
+                        lvalue = &pro.base;
+                        continue;
+                    },
+                    ProjectionElem::Field(_, ty) => {
+                        return adt::gen_struct_field_name(gen_referent_ty(ty, derefs), field);
+                    },
+                    _ => return field.to_string(),
+                }
+            },
+            Lvalue::Static(def_id) => data.tcx.lookup_item_type(def_id).ty,
+        };
+        return adt::gen_struct_field_name(gen_referent_ty(ty, derefs), field);
+    }
+}
+
+// Follows a number of references, or arrays to their elements, from a type
+fn gen_referent_ty(ty: Ty, derefs: usize) -> Ty {
+    let mut ty = ty;
+    for _ in 0..derefs {
+        ty = match ty.sty {
+            TypeVariants::TyRef(_, mt) | TypeVariants::TyRawPtr(mt) => mt.ty,
+            TypeVariants::TyBox(t) | TypeVariants::TyArray(t, _) | TypeVariants::TySlice(t) => t,
+            _ => return ty,
+        };
+    }
+    ty
+}
+
+/// Returns the name of the variable holding the value behind a reference.
+///
+/// # Arguments:
+/// * `name` - The name of the reference.
+///
+/// # Remarks:
+/// * References are assumed not to alias, so the value behind each is a distinct variable, named
+///   `*<name>`.
+///
+fn gen_deref_name(name: &str) -> String {
+    format!("*{}", name)
+}
 
-            // Get the index int from index_operand, then stick it in the VariableMappingData
-            VariableMappingData{ name: lvalue_name + "." + index.as_str(), var_type: lvalue_type }
+/// Renames each `old(x)` in a verification condition to `x`, the value on entry.
+///
+/// # Arguments:
+/// * `vc` - The verification condition.
+///
+/// # Remarks:
+/// * This is done once the weakest precondition is generated, since the body never changes an
+///   `old(x)`. For a call, it is done to the callee's postcondition once the values the callee
+///   changes are renamed, so that `old(x)` is the value before the call.
+///
+pub fn gen_entry_values(vc: &mut Expression) {
+    match *vc {
+        Expression::BinaryExpression(ref mut b) => {
+            gen_entry_values(&mut b.left);
+            gen_entry_values(&mut b.right);
         },
-        _=> unimplemented!(),
+        Expression::UnaryExpression(ref mut u) => gen_entry_values(&mut u.e),
+        Expression::Cast(ref mut c) => gen_entry_values(&mut c.e),
+        Expression::Select(ref mut s) => {
+            gen_entry_values(&mut s.array);
+            gen_entry_values(&mut s.index);
+        },
+        Expression::Store(ref mut s) => {
+            gen_entry_values(&mut s.array);
+            gen_entry_values(&mut s.index);
+            gen_entry_values(&mut s.value);
+        },
+        Expression::VariableMapping(ref mut v) => {
+            let name = strip_old_name(v.name.as_str()).map(|n| n.to_string());
+            if let Some(name) = name {
+                v.name = name;
+            }
+        },
+        _ => {},
     }
 }
 
+/// Returns the type behind a reference type (ie: `u32` for `&mut u32`).
+///
+/// # Arguments:
+/// * `ty` - The reference type, as a string.
+///
+/// # Remarks:
+///
+fn gen_pointee_ty(ty: &str) -> String {
+    let mut pointee = ty.trim_left_matches('&');
+    // Skip a named lifetime, if any
+    if pointee.starts_with('\'') {
+        pointee = match pointee.find(' ') {
+            Some(i) => &pointee[i + 1..],
+            None => rp_error!("Unsupported reference type: {}", ty),
+        };
+    }
+    if pointee.starts_with("mut ") {
+        pointee = &pointee[4..];
+    }
+    pointee.to_string()
+}
 
 /// Generates an Expression based on some operand, either a literal or some kind of variable, temp,
 /// or field
@@ -597,83 +1255,141 @@ fn gen_lvalue(lvalue: Lvalue, data: &mut MirData) -> VariableMappingData {
 /// * Returns a new expression generated from an operand
 ///
 /// # Remarks:
-/// * Current supported types: `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `bool`
+/// * Current supported types: `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `usize`,
+///   `bool`
 ///
 fn gen_expression(operand: &Operand, data: &mut MirData) -> Expression {
     match *operand {
-        // A variable/temp/field
+        // A variable/temp/field, or an element of an array
         Operand::Consume (ref l) => {
-            Expression::VariableMapping( gen_lvalue(l.clone(), data) )
+            match arrays::gen_index(l, data) {
+                Some((array, index)) => arrays::gen_select(&array, &index),
+                None => Expression::VariableMapping( gen_lvalue(l.clone(), data) ),
+            }
         },
         // A literal value
         Operand::Constant (ref c) => {
             match c.literal {
-                Literal::Value {ref value} => {
-                    match *value {
-                        ConstVal::Bool(ref const_bool) => {
-                            Expression::BooleanLiteral(*const_bool)
-                        }
-                        ConstVal::Integral(ref const_int) => {
-                            match *const_int {
-                                ConstInt::I8(i) => {
-                                    Expression::SignedBitVector( SignedBitVectorData {
-                                        size: 8,
-                                        value: i as i64
-                                    } )
-                                },
-                                ConstInt::I16(i) => {
-                                    Expression::SignedBitVector( SignedBitVectorData {
-                                        size: 16,
-                                        value: i as i64
-                                    } )
-                                },
-                                ConstInt::I32(i) => {
-                                    Expression::SignedBitVector( SignedBitVectorData {
-                                        size: 32,
-                                        value: i as i64
-                                    } )
-                                },
-                                ConstInt::I64(i) => {
-                                    Expression::SignedBitVector( SignedBitVectorData {
-                                        size: 64,
-                                        value: i as i64
-                                    } )
-                                },
-                                ConstInt::U8(u) => {
-                                    Expression::UnsignedBitVector( UnsignedBitVectorData {
-                                        size: 8,
-                                        value: u as u64
-                                    } )
-                                },
-                                ConstInt::U16(u) => {
-                                    Expression::UnsignedBitVector( UnsignedBitVectorData {
-                                        size: 16,
-                                        value: u as u64
-                                    } )
-                                },
-                                ConstInt::U32(u) => {
-                                    Expression::UnsignedBitVector( UnsignedBitVectorData {
-                                        size: 32,
-                                        value: u as u64
-                                    } )
-                                },
-                                ConstInt::U64(u) => {
-                                    Expression::UnsignedBitVector( UnsignedBitVectorData {
-                                        size: 64,
-                                        value: u as u64
-                                    } )
-                                },
-                                _ => unimplemented!(),
-                            }
-                        },
-                        _ => unimplemented!(),
-                    }
+                Literal::Value {value: ConstVal::Float(_)} => {
+                    rp_error!("Unsupported constant: floating-point values cannot be verified");
                 },
-                // Item {ref def_id, ref substs}
-                Literal::Item {..} => unimplemented!(),
-                // Promoted {ref index}
-                Literal::Promoted {..} => unimplemented!(),
+                Literal::Value {ref value} => gen_const_val(value),
+                // A const item
+                Literal::Item {def_id, substs} => consts::gen_item_const(def_id, substs, data),
+                // A constant promoted out of the function into its own MIR
+                Literal::Promoted {index} => consts::gen_promoted(index, false, data),
             }
         },
     }
 }
+
+/// Generates a literal Expression from a constant value
+///
+/// # Arguments:
+/// * `value` - The constant value to generate a new expression from.
+///
+/// # Return Value:
+/// * Returns a new literal expression
+///
+/// # Remarks:
+/// * Current supported types: `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `usize`,
+///   `bool`, `char`
+///
+fn gen_const_val(value: &ConstVal) -> Expression {
+    match *value {
+        ConstVal::Bool(ref const_bool) => {
+            Expression::BooleanLiteral(*const_bool)
+        }
+        // A char is its Unicode scalar value
+        ConstVal::Char(ref const_char) => {
+            Expression::Cast( CastData {
+                e: Box::new(Expression::UnsignedBitVector( UnsignedBitVectorData {
+                    size: 32,
+                    value: *const_char as u64
+                } )),
+                ty: "char".to_string()
+            } )
+        }
+        ConstVal::Integral(ref const_int) => {
+            match *const_int {
+                ConstInt::I8(i) => {
+                    Expression::SignedBitVector( SignedBitVectorData {
+                        size: 8,
+                        value: i as i64
+                    } )
+                },
+                ConstInt::I16(i) => {
+                    Expression::SignedBitVector( SignedBitVectorData {
+                        size: 16,
+                        value: i as i64
+                    } )
+                },
+                ConstInt::I32(i) => {
+                    Expression::SignedBitVector( SignedBitVectorData {
+                        size: 32,
+                        value: i as i64
+                    } )
+                },
+                ConstInt::I64(i) => {
+                    Expression::SignedBitVector( SignedBitVectorData {
+                        size: 64,
+                        value: i as i64
+                    } )
+                },
+                ConstInt::U8(u) => {
+                    Expression::UnsignedBitVector( UnsignedBitVectorData {
+                        size: 8,
+                        value: u as u64
+                    } )
+                },
+                ConstInt::U16(u) => {
+                    Expression::UnsignedBitVector( UnsignedBitVectorData {
+                        size: 16,
+                        value: u as u64
+                    } )
+                },
+                ConstInt::U32(u) => {
+                    Expression::UnsignedBitVector( UnsignedBitVectorData {
+                        size: 32,
+                        value: u as u64
+                    } )
+                },
+                ConstInt::U64(u) => {
+                    Expression::UnsignedBitVector( UnsignedBitVectorData {
+                        size: 64,
+                        value: u as u64
+                    } )
+                },
+                ConstInt::Usize(ref u) => arrays::gen_usize(arrays::gen_const_usize(u)),
+                ConstInt::Isize(ref i) => {
+                    gen_isize(match *i {
+                        ConstIsize::Is16(n) => n as i64,
+                        ConstIsize::Is32(n) => n as i64,
+                        ConstIsize::Is64(n) => n,
+                    })
+                },
+                _ => unimplemented!(),
+            }
+        },
+        _ => unimplemented!(),
+    }
+}
+
+/// Returns an `isize` constant.
+///
+/// # Arguments:
+/// * `value` - The value of the constant.
+///
+/// # Remarks:
+/// * Like a `usize` constant, it is written as a 64-bit literal, which is truncated to the pointer
+///   width.
+///
+fn gen_isize(value: i64) -> Expression {
+    Expression::Cast( CastData {
+        e: Box::new(Expression::SignedBitVector( SignedBitVectorData {
+            size: 64,
+            value: value
+        } )),
+        ty: "isize".to_string()
+    } )
+}
@@ -14,6 +14,7 @@ extern crate rustc_const_math;
 
 use expression::*;
 use rustc::mir::repr::*;
+use super::super::OverflowMode;
 
 
 /// Routes to appropriate overflow check (signed / unsigned)
@@ -22,26 +23,128 @@ pub fn overflow_check(wp: &Expression,
                       var: &VariableMappingData,
                       binop: &BinOp,
                       lvalue: &Expression,
-                      rvalue: &Expression)
+                      rvalue: &Expression,
+                      mode: OverflowMode,
+                      pointer_width: u8)
                       -> Expression {
-    let v = var.clone();
+    apply_overflow_mode(wp, &no_overflow(var, binop, lvalue, rvalue, pointer_width), mode)
+}
 
-    Expression::BinaryExpression( BinaryExpressionData {
-        op: BinaryOperator::And,
-        left: Box::new(wp.clone()),
-        right: Box::new(
-            match v.var_type.as_str() {
-                "i8" => signed_overflow(binop, 8u8, lvalue, rvalue),
-                "i16" => signed_overflow(binop, 16u8, lvalue, rvalue),
-                "i32" => signed_overflow(binop, 32u8, lvalue, rvalue),
-                "i64" => signed_overflow(binop, 64u8, lvalue, rvalue),
-                "u8" | "u16" | "u32" | "u64" => {
-                    unsigned_overflow(binop, lvalue, rvalue)
-                },
-                _ => panic!("Unsupported return type of binary operation: {}", v.var_type),
-            }
-        ),
-    })
+/// Combines wp with an Expression that holds when an operation does not overflow, according to
+/// how overflow is treated
+///
+/// # Arguments:
+/// * `wp` - The current weakest precondition.
+/// * `no_overflow` - Holds when the operation does not overflow.
+/// * `mode` - How overflow is treated.
+///
+/// # Return Value:
+/// * `Panic`: wp AND no_overflow
+/// * `Wrap`: wp, as bit-vector arithmetic already wraps around
+/// * `Ignore`: no_overflow IMPLIES wp
+///
+/// # Remarks:
+///
+pub fn apply_overflow_mode(wp: &Expression, no_overflow: &Expression, mode: OverflowMode)
+                           -> Expression {
+    match mode {
+        OverflowMode::Panic => {
+            Expression::BinaryExpression( BinaryExpressionData {
+                op: BinaryOperator::And,
+                left: Box::new(wp.clone()),
+                right: Box::new(no_overflow.clone()),
+            })
+        },
+        OverflowMode::Wrap => wp.clone(),
+        OverflowMode::Ignore => {
+            Expression::BinaryExpression( BinaryExpressionData {
+                op: BinaryOperator::Implication,
+                left: Box::new(no_overflow.clone()),
+                right: Box::new(wp.clone()),
+            })
+        },
+    }
+}
+
+/// Returns an Expression that holds when a binary operation does not overflow or underflow
+///
+/// # Arguments:
+/// * `var` - The variable the result of the operation is assigned to, which gives its type.
+/// * `binop` - The operation.
+/// * `lvalue` - The left-hand operand.
+/// * `rvalue` - The right-hand operand.
+/// * `pointer_width` - The width of `usize` and `isize`.
+///
+/// # Remarks:
+/// * The overflow flag of a checked operation is the negation of this Expression.
+/// * An `isize` operation is checked as the signed integer type of the same width.
+///
+pub fn no_overflow(var: &VariableMappingData,
+                   binop: &BinOp,
+                   lvalue: &Expression,
+                   rvalue: &Expression,
+                   pointer_width: u8)
+                   -> Expression {
+    match var.var_type.as_str() {
+        "i8" => signed_overflow(binop, 8u8, lvalue, rvalue),
+        "i16" => signed_overflow(binop, 16u8, lvalue, rvalue),
+        "i32" => signed_overflow(binop, 32u8, lvalue, rvalue),
+        "i64" => signed_overflow(binop, 64u8, lvalue, rvalue),
+        "isize" => {
+            signed_overflow(binop, pointer_width,
+                            &gen_fixed_width(lvalue, pointer_width),
+                            &gen_fixed_width(rvalue, pointer_width))
+        },
+        "u8" | "u16" | "u32" | "u64" | "usize" => {
+            unsigned_overflow(binop, lvalue, rvalue)
+        },
+        _ => panic!("Unsupported return type of binary operation: {}", var.var_type),
+    }
+}
+
+/// Returns a version of wp "And"ed together with a check that negating a value does not overflow
+///
+/// # Arguments:
+/// * `wp` - The current weakest precondition.
+/// * `var` - The variable the negated value is assigned to, which gives its type.
+/// * `value` - The value being negated.
+/// * `mode` - How overflow is treated.
+/// * `pointer_width` - The width of `isize`.
+///
+/// # Remarks:
+/// * Only the minimum value of a signed type overflows when negated; unsigned values cannot be
+///   negated, so no check is added for them.
+///
+pub fn negation_check(wp: &Expression,
+                      var: &VariableMappingData,
+                      value: &Expression,
+                      mode: OverflowMode,
+                      pointer_width: u8)
+                      -> Expression {
+    let size = match var.var_type.as_str() {
+        "i8" => 8u8,
+        "i16" => 16u8,
+        "i32" => 32u8,
+        "i64" => 64u8,
+        "isize" => {
+            return apply_overflow_mode(
+                wp,
+                &signed_neg(pointer_width, &gen_fixed_width(value, pointer_width)),
+                mode
+            );
+        },
+        _ => return wp.clone(),
+    };
+    apply_overflow_mode(wp, &signed_neg(size, value), mode)
+}
+
+// Converts an isize to the signed integer type of the same width, so it can be compared with
+// literals of that type
+fn gen_fixed_width(value: &Expression, pointer_width: u8) -> Expression {
+    Expression::Cast( CastData {
+        e: Box::new(value.clone()),
+        ty: format!("i{}", pointer_width)
+    } )
 }
 
 /// Routes to appropriate overflow check
@@ -481,6 +584,26 @@ fn signed_div(size: u8, lvalue: &Expression, rvalue: &Expression) -> Expression
     })
 }
 
+// -value overflows only when value is the minimum of its type
+fn signed_neg(size: u8, value: &Expression) -> Expression {
+    Expression::BinaryExpression( BinaryExpressionData{
+        op: BinaryOperator::NotEqual,
+        left: Box::new(value.clone()),
+        right: Box::new(
+            Expression::SignedBitVector( SignedBitVectorData{
+                size: size,
+                value: match size {
+                    8u8 => i8::min_value() as i64,
+                    16u8 => i16::min_value() as i64,
+                    32u8 => i32::min_value() as i64,
+                    64u8 => i64::min_value() as i64,
+                    _ => panic!("unsupported integer type"),
+                },
+            })
+        ),
+    })
+}
+
 /// Routes to appropriate overflow check
 // Unsigned: Match on the type of BinOp and call the correct function
 fn unsigned_overflow(binop: &BinOp, lvalue: &Expression, rvalue: &Expression) -> Expression {
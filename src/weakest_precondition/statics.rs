@@ -0,0 +1,195 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Functions to model `static` and `static mut` items as global variables.
+
+use super::super::MirData;
+use super::gen_const_val;
+use super::arrays::gen_usize;
+use std::process;
+use expression::*;
+use rustc::hir;
+use rustc::hir::def_id::DefId;
+use rustc::ty::{TyCtxt, Ty, TypeVariants};
+use rustc_const_eval::{eval_const_expr_partial, EvalHint};
+
+use errors::{ColorConfig, Handler};
+use syntax::codemap::CodeMap;
+use std::rc::Rc;
+
+/// Returns the global variable of a static.
+///
+/// # Arguments:
+/// * `def_id` - The `DefId` of the static.
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Return Value:
+/// * Returns a variable named by the static's path from the crate root (ie: `COUNTER` or
+///   `config::TABLE`), with the static's type.
+///
+/// # Remarks:
+/// * Statics are never given the prefix of an inlined callee, so every function shares them.
+///
+pub fn gen_static(def_id: DefId, data: &MirData) -> VariableMappingData {
+    VariableMappingData {
+        name: data.tcx.item_path_str(def_id),
+        var_type: data.tcx.lookup_item_type(def_id).ty.to_string()
+    }
+}
+
+/// Returns the global variables of the `static mut` items of the crate.
+///
+/// # Arguments:
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Remarks:
+/// * Any function may change a `static mut`, so a call gives each of them a fresh value.
+///
+pub fn find_mutable_statics(data: &MirData) -> Vec<VariableMappingData> {
+    let tcx = data.tcx;
+    let mut statics = Vec::new();
+    for (id, item) in tcx.map.krate().items.iter() {
+        if let hir::ItemStatic(_, hir::MutMutable, _) = item.node {
+            statics.push(gen_static(tcx.map.local_def_id(*id), data));
+        }
+    }
+    statics
+}
+
+/// Returns the name and type of each static of the crate.
+///
+/// # Arguments:
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Remarks:
+/// * Used to give the statics in a condition their types, so that they may be written without
+///   one (ie: `COUNTER` rather than `COUNTER: u32`).
+///
+pub fn gen_static_types<'a, 'tcx>(data: &MirData<'a, 'tcx>) -> Vec<(String, Ty<'tcx>)> {
+    let tcx = data.tcx;
+    let mut types = Vec::new();
+    for (id, item) in tcx.map.krate().items.iter() {
+        if let hir::ItemStatic(..) = item.node {
+            let def_id = tcx.map.local_def_id(*id);
+            types.push((tcx.item_path_str(def_id), tcx.lookup_item_type(def_id).ty));
+        }
+    }
+    types
+}
+
+/// Binds the immutable statics used in a verification condition to their values.
+///
+/// # Arguments:
+/// * `vc` - The verification condition.
+/// * `data` - Contains the type context from the MIR pass.
+///
+/// # Return Value:
+/// * Returns `(S_1 == v_1) -> .. -> (S_n == v_n) -> vc`, for each immutable static `S_i` used in
+///   `vc` with the initializer `v_i`.
+///
+/// # Remarks:
+/// * Scalar statics and arrays of scalars are supported. A static with any other initializer is
+///   left unconstrained, with a warning.
+///
+pub fn gen_static_values(vc: &Expression, data: &MirData) -> Expression {
+    let tcx = data.tcx;
+    let mut vc = vc.clone();
+    for (id, item) in tcx.map.krate().items.iter() {
+        if let hir::ItemStatic(_, hir::MutImmutable, ref init) = item.node {
+            let def_id = tcx.map.local_def_id(*id);
+            let var = gen_static(def_id, data);
+            if !uses_variable(&vc, var.name.as_str()) {
+                continue;
+            }
+            let value = match gen_static_value(&var, def_id, init, tcx) {
+                Some(value) => value,
+                None => {
+                    rp_warn!("The value of the static {} is not supported; it is left \
+                              unconstrained.", var.name);
+                    continue;
+                },
+            };
+            vc = Expression::BinaryExpression( BinaryExpressionData {
+                op: BinaryOperator::Implication,
+                left: Box::new(Expression::BinaryExpression( BinaryExpressionData {
+                    op: BinaryOperator::Equal,
+                    left: Box::new(Expression::VariableMapping(var)),
+                    right: Box::new(value)
+                } )),
+                right: Box::new(vc)
+            } );
+        }
+    }
+    vc
+}
+
+// Evaluates the initializer of a static. An array is every element stored into the static itself:
+// S == store(..store(S, 0, v_0).., n - 1, v_n-1)
+fn gen_static_value<'a, 'tcx>(var: &VariableMappingData,
+                              def_id: DefId,
+                              init: &hir::Expr,
+                              tcx: TyCtxt<'a, 'tcx, 'tcx>)
+                              -> Option<Expression> {
+    let elements: Vec<&hir::Expr> = match init.node {
+        hir::ExprVec(ref elements) => elements.iter().map(|e| &**e).collect(),
+        hir::ExprRepeat(ref element, _) => {
+            match tcx.lookup_item_type(def_id).ty.sty {
+                TypeVariants::TyArray(_, len) => (0..len).map(|_| &**element).collect(),
+                _ => return None,
+            }
+        },
+        _ => return gen_static_scalar(init, tcx),
+    };
+
+    let mut array = Expression::VariableMapping(var.clone());
+    for (index, element) in elements.iter().enumerate() {
+        let value = match gen_static_scalar(element, tcx) {
+            Some(value) => value,
+            None => return None,
+        };
+        array = Expression::Store( StoreData {
+            array: Box::new(array),
+            index: Box::new(gen_usize(index as u64)),
+            value: Box::new(value)
+        } );
+    }
+    Some(array)
+}
+
+// Evaluates a scalar initializer with rustc's const evaluator
+fn gen_static_scalar<'a, 'tcx>(init: &hir::Expr, tcx: TyCtxt<'a, 'tcx, 'tcx>)
+                               -> Option<Expression> {
+    match eval_const_expr_partial(tcx, init, EvalHint::ExprTypeChecked, None) {
+        Ok(value) => Some(gen_const_val(&value)),
+        Err(_) => None,
+    }
+}
+
+// Checks whether a variable, or a field of it, is used in an expression
+fn uses_variable(expression: &Expression, name: &str) -> bool {
+    match *expression {
+        Expression::BinaryExpression(ref b) => {
+            uses_variable(&b.left, name) || uses_variable(&b.right, name)
+        },
+        Expression::UnaryExpression(ref u) => uses_variable(&u.e, name),
+        Expression::Cast(ref c) => uses_variable(&c.e, name),
+        Expression::Select(ref s) => {
+            uses_variable(&s.array, name) || uses_variable(&s.index, name)
+        },
+        Expression::Store(ref s) => {
+            uses_variable(&s.array, name) || uses_variable(&s.index, name)
+            || uses_variable(&s.value, name)
+        },
+        Expression::VariableMapping(ref v) => {
+            v.name == name || (v.name.starts_with(name) && v.name[name.len()..].starts_with('.'))
+        },
+        _ => false,
+    }
+}
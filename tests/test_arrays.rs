@@ -0,0 +1,116 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="i: usize < 4usize && a[i: usize]: u32 == 7u32", post="return: u32 == 7u32")]
+fn valid_read(a: [u32; 4], i: usize) -> u32 {
+    a[i]
+}
+
+// Should be invalid: the index may be out of bounds
+#[condition(pre="true", post="true")]
+fn invalid_read(a: [u32; 4], i: usize) -> u32 {
+    a[i]
+}
+
+// Should be valid
+#[condition(pre="a[0usize]: u32 == 1u32 && a[1usize]: u32 == 2u32", post="return: u32 == 3u32")]
+fn valid_read_two(a: [u32; 4]) -> u32 {
+    a[0] + a[1]
+}
+
+// Should be invalid: different elements may hold different values
+#[condition(pre="true", post="return: bool == true")]
+fn invalid_read_two_equal(a: [u32; 4]) -> bool {
+    a[0] == a[1]
+}
+
+// Should be valid
+#[condition(pre="i: usize < len(s)", post="true")]
+fn valid_slice_read(s: &[u8], i: usize) -> u8 {
+    s[i]
+}
+
+// Should be invalid: the index may be equal to the length
+#[condition(pre="i: usize <= len(s)", post="true")]
+fn invalid_slice_read(s: &[u8], i: usize) -> u8 {
+    s[i]
+}
+
+// Should be valid
+#[condition(pre="i: usize < len(s)", post="s[i: usize]: u32 == 0u32")]
+fn valid_clear(s: &mut [u32], i: usize) {
+    s[i] = 0;
+}
+
+// Should be invalid: a different element may have been cleared
+#[condition(pre="i: usize < len(s) && j: usize < len(s)", post="s[j: usize]: u32 == 0u32")]
+fn invalid_clear(s: &mut [u32], i: usize, j: usize) {
+    s[i] = 0;
+}
+
+// Should be valid
+#[condition(pre="true", post="return: u32 == 6u32")]
+fn valid_literal() -> u32 {
+    let a = [1, 2, 3];
+    a[0] + a[1] + a[2]
+}
+
+// Should be valid
+#[condition(pre="true", post="return: u8 == 9u8")]
+fn valid_repeat() -> u8 {
+    let mut buf = [0u8; 4];
+    buf[3] = 9;
+    buf[0] + buf[3]
+}
+
+// Should be invalid: the array only has 4 elements
+#[condition(pre="true", post="true")]
+fn invalid_constant_index() -> u8 {
+    let buf = [0u8; 4];
+    let i = 4;
+    buf[i]
+}
+
+// Should be valid
+#[condition(pre="len(s) > 0usize", post="return: i32 == s[0usize]: i32")]
+fn valid_first(s: &[i32]) -> i32 {
+    s[0]
+}
+
+// Should be invalid: the slice may be empty
+#[condition(pre="true", post="return: i32 == s[0usize]: i32")]
+fn invalid_first(s: &[i32]) -> i32 {
+    s[0]
+}
+
+// Should be valid: a large repeated array is as easy as a small one
+#[condition(pre="i: usize < 100000usize", post="return: u32 == 7u32")]
+fn valid_large_repeat(i: usize) -> u32 {
+    let buf = [7u32; 100000];
+    buf[i]
+}
+
+// Should be valid: writing the elements of a slice does not change its length
+#[condition(pre="len(s) == 4usize", post="return: usize == 4usize")]
+#[invariant="i: usize <= 4usize"]
+fn valid_clear_loop(s: &mut [u32]) -> usize {
+    let mut i = 0;
+    while i < 4 {
+        s[i] = 0;
+        i += 1;
+    }
+    i
+}
@@ -0,0 +1,65 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="x: u32 < 1000u32", post="return: u32 == x: u32 + 1u32")]
+fn valid_increment(x: u32) -> u32 {
+    x + 1
+}
+
+// Should be valid
+#[condition(pre="x: u32 < 100u32", post="return: u32 == x: u32 + 2u32")]
+fn valid_increment_twice(x: u32) -> u32 {
+    let y = valid_increment(x);
+    valid_increment(y)
+}
+
+// Should be invalid: the precondition of valid_increment may not hold
+#[condition(pre="true", post="return: u32 == x: u32 + 1u32")]
+fn invalid_increment_unchecked(x: u32) -> u32 {
+    valid_increment(x)
+}
+
+// Should be invalid: the contract of valid_increment does not imply the postcondition
+#[condition(pre="x: u32 < 100u32", post="return: u32 == x: u32 + 3u32")]
+fn invalid_increment_twice(x: u32) -> u32 {
+    let y = valid_increment(x);
+    valid_increment(y)
+}
+
+// Should be valid
+#[condition(pre="true", post="return: i32 >= 0i32")]
+fn valid_clamp(a: i32) -> i32 {
+    if a < 0 { 0 } else { a }
+}
+
+// Should be valid: arguments are bound to the callee's names simultaneously
+#[condition(pre="true", post="return: i32 >= a: i32 && return: i32 >= b: i32")]
+fn valid_max(a: i32, b: i32) -> i32 {
+    if a > b { a } else { b }
+}
+
+// Should be valid
+#[condition(pre="true", post="return: i32 >= b: i32")]
+fn valid_max_swapped(a: i32, b: i32) -> i32 {
+    valid_max(b, a)
+}
+
+// Should be valid: constants can be passed as arguments
+#[condition(pre="true", post="return: i32 >= 0i32")]
+fn valid_max_constant(a: i32) -> i32 {
+    let c = valid_clamp(a);
+    valid_max(c, 0)
+}
@@ -0,0 +1,87 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid: truncation keeps the low bits
+#[condition(pre="x: u32 == 300u32", post="return: u8 == 44u8")]
+fn valid_truncate(x: u32) -> u8 {
+    x as u8
+}
+
+// Should be invalid: truncation loses the high bits
+#[condition(pre="true", post="return: u8 != 0u8 || x: u32 == 0u32")]
+fn invalid_truncate(x: u32) -> u8 {
+    x as u8
+}
+
+// Should be valid: sign extension preserves negative values
+#[condition(pre="x: i8 == -1i8", post="return: i64 == -1i64")]
+fn valid_sign_extend(x: i8) -> i64 {
+    x as i64
+}
+
+// Should be valid: zero extension of an unsigned value
+#[condition(pre="x: u8 == 255u8", post="return: u32 == 255u32")]
+fn valid_zero_extend(x: u8) -> u32 {
+    x as u32
+}
+
+// Should be valid: reinterpreting the bits of a negative value
+#[condition(pre="x: i8 == -1i8", post="return: u8 == 255u8")]
+fn valid_reinterpret(x: i8) -> u8 {
+    x as u8
+}
+
+// Should be invalid: a negative i8 does not zero extend
+#[condition(pre="x: i8 < 0i8", post="return: u32 <= 255u32")]
+fn invalid_signed_to_unsigned(x: i8) -> u32 {
+    x as u32
+}
+
+// Should be valid
+#[condition(pre="true",
+            post="(b: bool => return: u32 == 1u32) && (NOT b: bool => return: u32 == 0u32)")]
+fn valid_bool_to_int(b: bool) -> u32 {
+    b as u32
+}
+
+// Should be invalid: true is 1, not 0
+#[condition(pre="b: bool", post="return: u8 == 0u8")]
+fn invalid_bool_to_int(b: bool) -> u8 {
+    b as u8
+}
+
+// Should be valid: casts in the condition avoid overflow in the specification
+#[condition(pre="true", post="return: u64 == (x as u64) * (y as u64)")]
+fn valid_widening_mul(x: u32, y: u32) -> u64 {
+    (x as u64) * (y as u64)
+}
+
+// Should be invalid: the product of the u32 values may overflow
+#[condition(pre="true", post="return: u64 == (x * y) as u64")]
+fn invalid_widening_mul(x: u32, y: u32) -> u64 {
+    (x as u64) * (y as u64)
+}
+
+// Should be valid: -x as i64 is (-x) as i64
+#[condition(pre="x > -100i8 && x < 0i8", post="return == -x as i64")]
+fn valid_cast_negation(x: i8) -> i64 {
+    -(x as i64)
+}
+
+// Should be valid
+#[condition(pre="true", post="return as u32 <= 1u32 && (return as u32 == 1u32 <=> b)")]
+fn valid_bool_cast_in_condition(b: bool) -> bool {
+    b
+}
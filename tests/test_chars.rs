@@ -0,0 +1,66 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="c: char >= 'a' && c: char <= 'z'", post="return: bool == true")]
+fn valid_is_lower(c: char) -> bool {
+    c >= 'a' && c <= 'z'
+}
+
+// Should be invalid: 'z' is not lower than itself
+#[condition(pre="c: char >= 'a' && c: char <= 'z'", post="return: bool == true")]
+fn invalid_is_lower(c: char) -> bool {
+    c >= 'a' && c < 'z'
+}
+
+// Should be valid: every char is a Unicode scalar value
+#[condition(pre="true", post="return: u32 <= 1114111u32")]
+fn valid_scalar_value(c: char) -> u32 {
+    c as u32
+}
+
+// Should be invalid: a char can be above the surrogates
+#[condition(pre="true", post="return: u32 < 55296u32")]
+fn invalid_scalar_value(c: char) -> u32 {
+    c as u32
+}
+
+// Should be valid
+#[condition(pre="b: u8 < 128u8", post="return: char == 'A' IMPLIES b: u8 == 65u8")]
+fn valid_from_ascii(b: u8) -> char {
+    b as char
+}
+
+// Should be valid: casting to u8 keeps the low bits
+#[condition(pre="c: char == '\\n'", post="return: u8 == 10u8")]
+fn valid_to_byte(c: char) -> u8 {
+    c as u8
+}
+
+// Should be invalid: only the low bits of c are kept
+#[condition(pre="true", post="return: u8 == 10u8")]
+fn invalid_to_byte(c: char) -> u8 {
+    c as u8
+}
+
+// Should be valid
+#[condition(pre="true", post="return: u32 == 0u32 IMPLIES c: char == ' '")]
+fn valid_match(c: char) -> u32 {
+    match c {
+        ' ' => 0,
+        'a' => 1,
+        _ => 2,
+    }
+}
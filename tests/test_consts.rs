@@ -0,0 +1,53 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+const MAX_LEN: u32 = 16;
+const LIMIT: u32 = MAX_LEN * 4;
+
+mod limits {
+    pub const STEP: i32 = 3;
+}
+
+// Should be valid
+#[condition(pre="x: u32 < MAX_LEN: u32", post="return: u32 < LIMIT: u32")]
+fn valid_const_item(x: u32) -> u32 {
+    x + MAX_LEN
+}
+
+// Should be invalid: x + MAX_LEN may reach LIMIT
+#[condition(pre="x: u32 < LIMIT: u32", post="return: u32 < LIMIT: u32")]
+fn invalid_const_item(x: u32) -> u32 {
+    x + MAX_LEN
+}
+
+// Should be valid
+#[condition(pre="x: i32 < 100i32", post="return: i32 == x: i32 + limits::STEP: i32")]
+fn valid_const_path(x: i32) -> i32 {
+    x + limits::STEP
+}
+
+// Should be valid: the value behind a promoted reference is the constant
+#[condition(pre="true", post="return: u32 == 7u32")]
+fn valid_promoted() -> u32 {
+    let r: &'static u32 = &7;
+    *r
+}
+
+// Should be invalid
+#[condition(pre="true", post="return: u32 == 8u32")]
+fn invalid_promoted() -> u32 {
+    let r: &'static u32 = &7;
+    *r
+}
@@ -0,0 +1,52 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+struct Guard {
+    value: u32,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) { }
+}
+
+// Should be valid: the guard is dropped before returning
+#[condition(pre="true", post="return: u32 == x: u32")]
+fn valid_drop(x: u32) -> u32 {
+    let g = Guard { value: x };
+    g.value
+}
+
+// Should be invalid
+#[condition(pre="true", post="return: u32 == x: u32 + 1u32")]
+fn invalid_drop(x: u32) -> u32 {
+    let g = Guard { value: x };
+    g.value
+}
+
+// Should be valid: the old guard is dropped and replaced
+#[condition(pre="true", post="return: u32 == y: u32")]
+fn valid_drop_and_replace(x: u32, y: u32) -> u32 {
+    let mut g = Guard { value: x };
+    g = Guard { value: y };
+    g.value
+}
+
+// Should be invalid: the guard was replaced
+#[condition(pre="true", post="return: u32 == x: u32")]
+fn invalid_drop_and_replace(x: u32, y: u32) -> u32 {
+    let mut g = Guard { value: x };
+    g = Guard { value: y };
+    g.value
+}
@@ -0,0 +1,125 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+// Should be valid
+#[condition(pre="true", post="return is Some(v) && v: i32 == x: i32")]
+fn valid_wrap(x: i32) -> Option<i32> {
+    Some(x)
+}
+
+// Should be valid
+#[condition(pre="x is Some(v) && v: u32 > 5u32", post="return: u32 > 5u32")]
+fn valid_unwrap_or_zero(x: Option<u32>) -> u32 {
+    match x {
+        Some(v) => v,
+        None => 0,
+    }
+}
+
+// Should be invalid: x may be None
+#[condition(pre="true", post="return: u32 > 0u32")]
+fn invalid_unwrap_or_zero(x: Option<u32>) -> u32 {
+    match x {
+        Some(v) => v,
+        None => 0,
+    }
+}
+
+// Should be valid
+#[condition(pre="true",
+            post="(return is Some(v) && v: u32 < x: u32) || (is_none(return) && x: u32 == 0u32)")]
+fn valid_decrement(x: u32) -> Option<u32> {
+    if x > 0 {
+        Some(x - 1)
+    } else {
+        None
+    }
+}
+
+// Should be invalid: returns None for x == 1 as well
+#[condition(pre="true", post="is_some(return) || x: u32 == 0u32")]
+fn invalid_decrement(x: u32) -> Option<u32> {
+    if x > 1 {
+        Some(x - 1)
+    } else {
+        None
+    }
+}
+
+// Should be valid
+#[condition(pre="r is Ok(n) && n: u32 == 7u32", post="return: u32 == 7u32")]
+fn valid_unwrap_result(r: Result<u32, bool>) -> u32 {
+    match r {
+        Ok(n) => n,
+        Err(_) => 0,
+    }
+}
+
+// Should be invalid: r may be an Err
+#[condition(pre="true", post="is_ok(r) => return: u32 == 7u32")]
+fn invalid_unwrap_result(r: Result<u32, bool>) -> u32 {
+    match r {
+        Ok(_) => 0,
+        Err(_) => 7,
+    }
+}
+
+// Should be valid
+#[condition(pre="true", post="(return is Zero) <=> (x: i32 == 0i32)")]
+fn valid_sign(x: i32) -> Sign {
+    if x < 0 {
+        Sign::Negative
+    } else if x == 0 {
+        Sign::Zero
+    } else {
+        Sign::Positive
+    }
+}
+
+// Should be invalid: 0 is classified as Positive
+#[condition(pre="true", post="(return is Positive) => (x: i32 > 0i32)")]
+fn invalid_sign(x: i32) -> Sign {
+    if x < 0 {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    }
+}
+
+// Should be valid: an enum argument always holds one of its variants
+#[condition(pre="true", post="is_some(x) || is_none(x)")]
+fn valid_some_or_none(x: Option<u32>) {
+}
+
+// Should be valid: a match on an enum argument has no case beyond its variants
+#[condition(pre="true", post="return: i32 >= -1i32 && return: i32 <= 1i32")]
+fn valid_sign_value(s: Sign) -> i32 {
+    match s {
+        Sign::Negative => -1,
+        Sign::Zero => 0,
+        Sign::Positive => 1,
+    }
+}
+
+// Should be invalid: x may be None
+#[condition(pre="true", post="is_some(x)")]
+fn invalid_always_some(x: Option<u32>) {
+}
@@ -0,0 +1,91 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+static mut COUNTER: u32 = 0;
+
+// Should be valid: the types are found from the signature
+#[condition(pre="x < 100u32 && y < 100u32", post="return == x + y")]
+fn valid_add(x: u32, y: u32) -> u32 {
+    x + y
+}
+
+// Should be invalid
+#[condition(pre="x < 100u32 && y < 100u32", post="return == x")]
+fn invalid_add(x: u32, y: u32) -> u32 {
+    x + y
+}
+
+// Should be valid: explicit types may still be given
+#[condition(pre="x: i32 > 0i32 && x < 100i32 && y >= 0i32 && y < 10i32", post="return: i32 > y")]
+fn valid_mixed(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+// Should be valid
+#[condition(pre="*x < 100u32", post="*x == old(*x) + 1u32")]
+fn valid_increment(x: &mut u32) {
+    *x = *x + 1;
+}
+
+// Should be valid
+#[condition(pre="p.x < 100u32 && p.y < 100u32", post="return < 200u32")]
+fn valid_sum(p: Point) -> u32 {
+    p.x + p.y
+}
+
+// Should be valid
+#[condition(pre="i < len(s)", post="s[i] == 0u32")]
+fn valid_clear(s: &mut [u32], i: usize) {
+    s[i] = 0;
+}
+
+// Should be valid: a bound name has the type of its field
+#[condition(pre="true", post="return is Some(v) && v == x")]
+fn valid_wrap(x: i32) -> Option<i32> {
+    Some(x)
+}
+
+// Should be valid
+#[condition(pre="COUNTER < 1000u32", post="COUNTER == old(COUNTER) + 1u32")]
+fn valid_bump() {
+    unsafe { COUNTER = COUNTER + 1; }
+}
+
+// Should be valid: locals in an invariant are typed by their declarations
+#[condition(pre="n <= 100u32", post="return == n")]
+#[invariant="i <= n"]
+fn valid_count_up(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+// Should be invalid
+#[condition(pre="true", post="return == n")]
+#[invariant="i < n"]
+fn invalid_count_up(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
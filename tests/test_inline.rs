@@ -0,0 +1,99 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn quadruple(x: u32) -> u32 {
+    double(double(x))
+}
+
+fn pick(c: bool, a: i32, b: i32) -> i32 {
+    if c { a } else { b }
+}
+
+fn bump(x: &mut u32) {
+    *x = *x + 1;
+}
+
+// Should be valid
+#[condition(pre="x: u32 < 1000u32", post="return: u32 == x: u32 * 2u32")]
+#[inline_depth="1"]
+fn valid_double(x: u32) -> u32 {
+    double(x)
+}
+
+// Should be invalid: double may overflow
+#[condition(pre="true", post="return: u32 == x: u32 * 2u32")]
+#[inline_depth="1"]
+fn invalid_double(x: u32) -> u32 {
+    double(x)
+}
+
+// Should be valid
+#[condition(pre="x: u32 < 1000u32", post="return: u32 == x: u32 * 4u32")]
+#[inline_depth="2"]
+fn valid_quadruple(x: u32) -> u32 {
+    quadruple(x)
+}
+
+// Should be valid: each inlined call gets fresh names
+#[condition(pre="x: u32 < 1000u32", post="return: u32 == x: u32 * 4u32")]
+#[inline_depth="1"]
+fn valid_double_twice(x: u32) -> u32 {
+    let y = double(x);
+    double(y)
+}
+
+// Should be valid: the arguments are swapped, and c is false
+#[condition(pre="true", post="return: i32 == a: i32")]
+#[inline_depth="1"]
+fn valid_pick_swapped(a: i32, b: i32) -> i32 {
+    pick(false, b, a)
+}
+
+// Should be invalid
+#[condition(pre="true", post="return: i32 == a: i32")]
+#[inline_depth="1"]
+fn invalid_pick(c: bool, a: i32, b: i32) -> i32 {
+    pick(c, a, b)
+}
+
+// Should be valid: the write through the argument changes the local
+#[condition(pre="true", post="return: u32 == 6u32")]
+#[inline_depth="1"]
+fn valid_bump_local() -> u32 {
+    let mut y = 5;
+    bump(&mut y);
+    y
+}
+
+// Should be valid: the write through the argument changes the caller's argument
+#[condition(pre="*x: u32 < 1000u32", post="*x: u32 == old(*x: u32) + 2u32")]
+#[inline_depth="1"]
+fn valid_bump_twice(x: &mut u32) {
+    bump(x);
+    bump(x);
+}
+
+// Should be invalid: the local is changed by the inlined call
+#[condition(pre="true", post="return: u32 == 5u32")]
+#[inline_depth="1"]
+fn invalid_bump_local() -> u32 {
+    let mut y = 5;
+    bump(&mut y);
+    y
+}
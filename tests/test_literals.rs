@@ -0,0 +1,62 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid: an unsuffixed literal has the type of its operand
+#[condition(pre="x < 100", post="return == x + 1")]
+fn valid_unsuffixed(x: i32) -> i32 {
+    x + 1
+}
+
+// Should be invalid
+#[condition(pre="x < 100", post="return == x + 2")]
+fn invalid_unsuffixed(x: i32) -> i32 {
+    x + 1
+}
+
+// Should be valid
+#[condition(pre="true", post="return == 0xFFu8")]
+fn valid_hex() -> u8 {
+    255
+}
+
+// Should be valid
+#[condition(pre="x <= 0b1010u8", post="return <= 0xAu8")]
+fn valid_binary(x: u8) -> u8 {
+    x
+}
+
+// Should be valid
+#[condition(pre="x < 1_000_000u64", post="return < 2_000_000u64")]
+fn valid_underscores(x: u64) -> u64 {
+    x * 2
+}
+
+// Should be valid: a negative literal takes the type of its operand
+#[condition(pre="x > -0x80 && x < 0", post="return > 0")]
+fn valid_negative(x: i8) -> i8 {
+    -x
+}
+
+// Should be valid: array indices are usize
+#[condition(pre="len(a) > 0", post="return == a[0]")]
+fn valid_first(a: &[u32]) -> u32 {
+    a[0]
+}
+
+// Should be valid: the shift amount has the type of the value shifted
+#[condition(pre="x < 0x100u32", post="return == x << 4")]
+fn valid_shift(x: u32) -> u32 {
+    x << 4
+}
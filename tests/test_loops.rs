@@ -0,0 +1,107 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="n: u32 <= 100u32", post="return: u32 == n: u32")]
+#[invariant="i: u32 <= n: u32"]
+fn valid_count_up(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+// Should be invalid: the invariant does not hold on entry when n is 0
+#[condition(pre="n: u32 <= 100u32", post="return: u32 == n: u32")]
+#[invariant="i: u32 < n: u32"]
+fn invalid_count_up_entry(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+// Should be invalid: the invariant is not strong enough to prove the postcondition
+#[condition(pre="n: u32 <= 100u32", post="return: u32 == n: u32")]
+#[invariant="i: u32 <= 100u32"]
+fn invalid_count_up_exit(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+// Should be valid
+#[condition(pre="n: u32 <= 1000u32", post="return: u32 == n: u32 * 2u32")]
+#[invariant="(i: u32 <= n: u32) && (sum: u32 == i: u32 * 2u32)"]
+fn valid_double_by_loop(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        sum += 2;
+        i += 1;
+    }
+    sum
+}
+
+// Should be valid: each invariant is attached to the loop at the same position in the source
+#[condition(pre="(n: u32 <= 100u32) && (m: u32 <= 100u32)", post="return: u32 == n: u32 + m: u32")]
+#[invariant="i: u32 <= n: u32"]
+#[invariant="(j: u32 <= m: u32) && (i: u32 == n: u32)"]
+fn valid_two_loops(n: u32, m: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    let mut j = 0;
+    while j < m {
+        j += 1;
+    }
+    i + j
+}
+
+#[condition(pre="true", post="*x: u32 <= 100u32")]
+fn valid_set_below(x: &mut u32) {
+    *x = 100;
+}
+
+// Should be valid: the invariant holds whatever the callee sets the local to
+#[condition(pre="n: u32 <= 100u32", post="return: u32 <= 500u32")]
+#[invariant="(i: u32 <= n: u32) && (y: u32 <= 500u32)"]
+fn valid_loop_call_set_local(n: u32) -> u32 {
+    let mut y = 500;
+    let mut i = 0;
+    while i < n {
+        valid_set_below(&mut y);
+        i += 1;
+    }
+    y
+}
+
+// Should be invalid: the callee may change the local on any iteration
+#[condition(pre="n: u32 <= 100u32", post="return: u32 == 500u32")]
+#[invariant="i: u32 <= n: u32"]
+fn invalid_loop_call_local_unchanged(n: u32) -> u32 {
+    let mut y = 500;
+    let mut i = 0;
+    while i < n {
+        valid_set_below(&mut y);
+        i += 1;
+    }
+    y
+}
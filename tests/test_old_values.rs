@@ -0,0 +1,60 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="*x: u32 < 100u32", post="*x: u32 == old(*x: u32) + 1u32")]
+fn valid_increment(x: &mut u32) {
+    *x = *x + 1;
+}
+
+// Should be invalid: the value behind x is changed
+#[condition(pre="*x: u32 < 100u32", post="*x: u32 == old(*x: u32)")]
+fn invalid_increment(x: &mut u32) {
+    *x = *x + 1;
+}
+
+// Should be valid: old(..) may contain any expression
+#[condition(pre="*x: u32 < 100u32 && y: u32 < 100u32", post="*x: u32 == old(*x: u32 + y: u32)")]
+fn valid_add_assign(x: &mut u32, y: u32) {
+    *x = *x + y;
+}
+
+// Should be valid
+#[condition(pre="true", post="*a: u32 == old(*b: u32) && *b: u32 == old(*a: u32)")]
+fn valid_swap(a: &mut u32, b: &mut u32) {
+    let t = *a;
+    *a = *b;
+    *b = t;
+}
+
+// Should be invalid: only a is changed
+#[condition(pre="true", post="*a: u32 == old(*b: u32) && *b: u32 == old(*a: u32)")]
+fn invalid_swap(a: &mut u32, b: &mut u32) {
+    *a = *b;
+}
+
+// Should be valid: in the callee's postcondition, old(*x) is the value before each call
+#[condition(pre="*x: u32 < 50u32", post="*x: u32 == old(*x: u32) + 2u32")]
+fn valid_increment_twice(x: &mut u32) {
+    valid_increment(x);
+    valid_increment(x);
+}
+
+// Should be invalid
+#[condition(pre="*x: u32 < 50u32", post="*x: u32 == old(*x: u32) + 1u32")]
+fn invalid_increment_twice(x: &mut u32) {
+    valid_increment(x);
+    valid_increment(x);
+}
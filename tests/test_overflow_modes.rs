@@ -0,0 +1,84 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be invalid: the addition may overflow
+#[condition(pre="true", post="true")]
+#[overflow="panic"]
+fn invalid_add_panic(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+// Should be valid: the addition wraps around
+#[condition(pre="true", post="true")]
+#[overflow="wrap"]
+fn valid_add_wrap(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+// Should be valid: 255 + 1 wraps around to 0
+#[condition(pre="x: u8 == 255u8", post="return: u8 == 0u8")]
+#[overflow="wrap"]
+fn valid_wrap_to_zero(x: u8) -> u8 {
+    x + 1
+}
+
+// Should be invalid: the result may have wrapped around
+#[condition(pre="true", post="return: u8 >= x: u8")]
+#[overflow="wrap"]
+fn invalid_wrap_increases(x: u8) -> u8 {
+    x + 1
+}
+
+// Should be valid: the addition is assumed not to overflow
+#[condition(pre="true", post="return: u8 >= x: u8")]
+#[overflow="ignore"]
+fn valid_ignore_increases(x: u8) -> u8 {
+    x + 1
+}
+
+// Should be valid: negating i32::MIN wraps around to itself
+#[condition(pre="x: i32 == i32::MIN", post="return: i32 == x: i32")]
+#[overflow="wrap"]
+fn valid_negate_wrap(x: i32) -> i32 {
+    -x
+}
+
+// Should be valid: negating any value wraps around
+#[condition(pre="true", post="true")]
+#[overflow="wrap"]
+fn valid_negate_any_wrap(x: i64) -> i64 {
+    -x
+}
+
+// Should be invalid: negating i8::MIN wraps around to a negative value
+#[condition(pre="x: i8 < 0i8", post="return: i8 > 0i8")]
+#[overflow="wrap"]
+fn invalid_negate_positive_wrap(x: i8) -> i8 {
+    -x
+}
+
+// Should be valid: the negation is assumed not to overflow
+#[condition(pre="x: i8 < 0i8", post="return: i8 > 0i8")]
+#[overflow="ignore"]
+fn valid_negate_positive_ignore(x: i8) -> i8 {
+    -x
+}
+
+// Should be invalid: division by zero panics in every mode
+#[condition(pre="true", post="true")]
+#[overflow="wrap"]
+fn invalid_divide_wrap(x: u32, y: u32) -> u32 {
+    x / y
+}
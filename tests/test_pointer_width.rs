@@ -0,0 +1,67 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="x: usize < usize::MAX", post="return: usize > x: usize")]
+fn valid_usize_increment(x: usize) -> usize {
+    x + 1
+}
+
+// Should be invalid: the addition may overflow
+#[condition(pre="true", post="return: usize > x: usize")]
+fn invalid_usize_increment(x: usize) -> usize {
+    x + 1
+}
+
+// Should be valid: usize::MAX is u32::MAX on a 32-bit target
+#[condition(pre="true", post="return: usize == usize::MAX")]
+#[pointer_width="32"]
+fn valid_usize_max_32() -> usize {
+    4294967295
+}
+
+// Should be invalid: the addition overflows on a 32-bit target
+#[condition(pre="x: usize == 4294967295usize", post="true")]
+#[pointer_width="32"]
+fn invalid_overflow_32(x: usize) -> usize {
+    x + 1
+}
+
+// Should be valid: the addition does not overflow on a 64-bit target
+#[condition(pre="x: usize == 4294967295usize", post="return: usize == 4294967296usize")]
+#[pointer_width="64"]
+fn valid_no_overflow_64(x: usize) -> usize {
+    x + 1
+}
+
+// Should be valid
+#[condition(pre="x: isize > isize::MIN", post="return: isize >= 0isize")]
+fn valid_isize_abs(x: isize) -> isize {
+    if x < 0 { -x } else { x }
+}
+
+// Should be invalid: isize::MIN cannot be negated
+#[condition(pre="true", post="return: isize >= 0isize")]
+#[pointer_width="32"]
+fn invalid_isize_abs(x: isize) -> isize {
+    if x < 0 { -x } else { x }
+}
+
+// Should be valid
+#[condition(pre="x: isize < isize::MAX", post="return: isize > x: isize")]
+#[pointer_width="32"]
+fn valid_isize_increment(x: isize) -> isize {
+    x + 1
+}
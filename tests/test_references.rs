@@ -0,0 +1,108 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="true", post="*x: u32 == 5u32")]
+fn valid_set(x: &mut u32) {
+    *x = 5;
+}
+
+// Should be invalid: the value behind x is only changed when c holds
+#[condition(pre="true", post="*x: u32 == 5u32")]
+fn invalid_set(x: &mut u32, c: bool) {
+    if c {
+        *x = 5;
+    }
+}
+
+// Should be valid
+#[condition(pre="*x: u32 < 100u32 && y: u32 < 100u32", post="*x: u32 < 200u32")]
+fn valid_add_assign(x: &mut u32, y: u32) {
+    *x = *x + y;
+}
+
+// Should be invalid: the addition may overflow
+#[condition(pre="true", post="true")]
+fn invalid_add_assign(x: &mut u32, y: u32) {
+    *x = *x + y;
+}
+
+// Should be valid
+#[condition(pre="*x: i32 == 3i32", post="return: i32 == 3i32")]
+fn valid_read(x: &i32) -> i32 {
+    *x
+}
+
+// Should be valid: writes through a local borrow update the borrowed variable
+#[condition(pre="true", post="return: u32 == 7u32")]
+fn valid_local_borrow() -> u32 {
+    let mut y = 1;
+    {
+        let r = &mut y;
+        *r = 7;
+    }
+    y
+}
+
+// Should be invalid: the write through the borrow changes y
+#[condition(pre="true", post="return: u32 == 1u32")]
+fn invalid_local_borrow() -> u32 {
+    let mut y = 1;
+    {
+        let r = &mut y;
+        *r = 7;
+    }
+    y
+}
+
+// Should be valid: a reborrow writes through to the argument
+#[condition(pre="true", post="*x: u32 == 9u32")]
+fn valid_reborrow(x: &mut u32) {
+    let r = &mut *x;
+    *r = 9;
+}
+
+// Should be valid: the callee's postcondition describes the new value
+#[condition(pre="*x: u32 < 100u32", post="*x: u32 < 101u32")]
+fn valid_call_set_below(x: &mut u32) {
+    valid_set_below(x);
+}
+
+#[condition(pre="true", post="*x: u32 <= 100u32")]
+fn valid_set_below(x: &mut u32) {
+    *x = 100;
+}
+
+// Should be invalid: the callee may change the value behind x
+#[condition(pre="*x: u32 == 1u32", post="*x: u32 == 1u32")]
+fn invalid_call_unchanged(x: &mut u32) {
+    valid_set_below(x);
+}
+
+// Should be valid: the callee's postcondition describes the new value of the local
+#[condition(pre="true", post="return: u32 <= 100u32")]
+fn valid_call_set_local() -> u32 {
+    let mut y = 500;
+    valid_set_below(&mut y);
+    y
+}
+
+// Should be invalid: the callee may change the local passed to it
+#[condition(pre="true", post="return: u32 == 500u32")]
+fn invalid_call_local_unchanged() -> u32 {
+    let mut y = 500;
+    valid_set_below(&mut y);
+    y
+}
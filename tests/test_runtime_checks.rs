@@ -0,0 +1,104 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="y: u32 != 0u32", post="true")]
+fn valid_divide(x: u32, y: u32) -> u32 {
+    x / y
+}
+
+// Should be invalid: the divisor may be zero
+#[condition(pre="true", post="true")]
+fn invalid_divide(x: u32, y: u32) -> u32 {
+    x / y
+}
+
+// Should be invalid: the divisor may be zero
+#[condition(pre="true", post="true")]
+fn invalid_remainder(x: u64, y: u64) -> u64 {
+    x % y
+}
+
+// Should be valid
+#[condition(pre="y: i32 > 0i32", post="true")]
+fn valid_signed_divide(x: i32, y: i32) -> i32 {
+    x / y
+}
+
+// Should be invalid: i32::MIN / -1 overflows
+#[condition(pre="y: i32 != 0i32", post="true")]
+fn invalid_signed_divide(x: i32, y: i32) -> i32 {
+    x / y
+}
+
+// Should be valid
+#[condition(pre="x: u8 < 100u8 && y: u8 < 100u8", post="return: u8 == x: u8 + y: u8")]
+fn valid_checked_add(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+// Should be invalid: the addition may overflow, though the postcondition holds
+#[condition(pre="true", post="true")]
+fn invalid_checked_add(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+// Should be invalid: the subtraction may underflow
+#[condition(pre="x: i16 < 0i16", post="true")]
+fn invalid_checked_sub(x: i16, y: i16) -> i16 {
+    x - y
+}
+
+// Should be valid
+#[condition(pre="y: u32 < 32u32", post="true")]
+fn valid_shift_left(x: u32, y: u32) -> u32 {
+    x << y
+}
+
+// Should be invalid: the shift amount may be 32 or more
+#[condition(pre="true", post="true")]
+fn invalid_shift_left(x: u32, y: u32) -> u32 {
+    x << y
+}
+
+// Should be valid
+#[condition(pre="y: u8 < 64u8", post="true")]
+fn valid_mixed_width_shift_right(x: u64, y: u8) -> u64 {
+    x >> y
+}
+
+// Should be invalid: the shift amount may be 8 or more
+#[condition(pre="y: u64 < 64u64", post="true")]
+fn invalid_mixed_width_shift_left(x: u8, y: u64) -> u8 {
+    x << y
+}
+
+// Should be invalid: the shift amount may be negative
+#[condition(pre="y: i32 < 16i32", post="true")]
+fn invalid_signed_shift_amount(x: i16, y: i32) -> i16 {
+    x >> y
+}
+
+// Should be valid
+#[condition(pre="x: i32 > 0i32", post="return: i32 < 0i32")]
+fn valid_negate(x: i32) -> i32 {
+    -x
+}
+
+// Should be invalid: negating i64::MIN overflows
+#[condition(pre="true", post="true")]
+fn invalid_negate(x: i64) -> i64 {
+    -x
+}
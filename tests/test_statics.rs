@@ -0,0 +1,61 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+static LIMIT: u32 = 100;
+static TABLE: [u8; 4] = [1, 2, 4, 8];
+static mut COUNTER: u32 = 0;
+
+// Should be valid: LIMIT is bound to its initializer
+#[condition(pre="x: u32 < 10u32", post="return: u32 < 110u32")]
+fn valid_static(x: u32) -> u32 {
+    x + LIMIT
+}
+
+// Should be invalid
+#[condition(pre="x: u32 < 10u32", post="return: u32 < 100u32")]
+fn invalid_static(x: u32) -> u32 {
+    x + LIMIT
+}
+
+// Should be valid: the table is bound to its initializer
+#[condition(pre="i: usize < 4usize", post="return: u8 > 0u8")]
+fn valid_table(i: usize) -> u8 {
+    TABLE[i]
+}
+
+// Should be valid
+#[condition(pre="COUNTER: u32 < 1000u32", post="COUNTER: u32 == old(COUNTER: u32) + 1u32")]
+fn valid_bump() {
+    unsafe { COUNTER = COUNTER + 1; }
+}
+
+// Should be invalid: COUNTER may overflow
+#[condition(pre="true", post="COUNTER: u32 == old(COUNTER: u32) + 1u32")]
+fn invalid_bump() {
+    unsafe { COUNTER = COUNTER + 1; }
+}
+
+// Should be invalid: COUNTER is changed
+#[condition(pre="COUNTER: u32 < 1000u32", post="COUNTER: u32 == old(COUNTER: u32)")]
+fn invalid_unchanged() {
+    unsafe { COUNTER = COUNTER + 1; }
+}
+
+// Should be valid: the callee's postcondition says how COUNTER changes
+#[condition(pre="COUNTER: u32 < 100u32", post="COUNTER: u32 == old(COUNTER: u32) + 2u32")]
+fn valid_bump_twice() {
+    valid_bump();
+    valid_bump();
+}
@@ -0,0 +1,92 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+struct Buffer {
+    len: u64,
+    full: bool,
+}
+
+struct Wrapper {
+    inner: Buffer,
+    id: i32,
+}
+
+// Should be valid
+#[condition(pre="p.x: u32 < 100u32 && p.y: u32 < 100u32", post="return: u32 < 200u32")]
+fn valid_sum(p: Point) -> u32 {
+    p.x + p.y
+}
+
+// Should be invalid: the addition may overflow
+#[condition(pre="true", post="true")]
+fn invalid_sum(p: Point) -> u32 {
+    p.x + p.y
+}
+
+// Should be valid
+#[condition(pre="true", post="return.x: u32 == a: u32 && return.y: u32 == b: u32")]
+fn valid_new(a: u32, b: u32) -> Point {
+    Point { x: a, y: b }
+}
+
+// Should be invalid: the fields are swapped
+#[condition(pre="true", post="return.x: u32 == a: u32")]
+fn invalid_new(a: u32, b: u32) -> Point {
+    Point { x: b, y: a }
+}
+
+// Should be valid
+#[condition(pre="true", post="return.inner.len: u64 == 0u64 && return.inner.full: bool == false")]
+fn valid_nested() -> Wrapper {
+    let b = Buffer { len: 0, full: false };
+    Wrapper { inner: b, id: 1 }
+}
+
+// Should be valid
+#[condition(pre="w.inner.len: u64 > 10u64", post="return: u64 > 9u64")]
+fn valid_nested_read(w: Wrapper) -> u64 {
+    w.inner.len - 1
+}
+
+// Should be invalid: the subtraction may underflow
+#[condition(pre="true", post="true")]
+fn invalid_nested_read(w: Wrapper) -> u64 {
+    w.inner.len - 1
+}
+
+// Should be valid
+#[condition(pre="true", post="return.y: u32 == 3u32 && return.x: u32 == p.x: u32")]
+fn valid_field_assign(p: Point) -> Point {
+    let mut q = p;
+    q.y = 3;
+    q
+}
+
+// Should be valid
+#[condition(pre="*p.x: u32 == 4u32", post="*p.x: u32 == 5u32")]
+fn valid_write_through(p: &mut Point) {
+    p.x = p.x + 1;
+}
+
+// Should be invalid: only x is changed
+#[condition(pre="true", post="*p.y: u32 == 5u32")]
+fn invalid_write_through(p: &mut Point) {
+    p.x = 5;
+}
@@ -0,0 +1,54 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="x: u32 <= 100u32", post="return: u32 == x: u32 * 2u32")]
+fn valid_match_double(x: u32) -> u32 {
+    match x {
+        0 => 0,
+        1 => 2,
+        2 => 4,
+        _ => x * 2,
+    }
+}
+
+// Should be invalid: the otherwise branch does not double x
+#[condition(pre="x: u32 <= 100u32", post="return: u32 == x: u32 * 2u32")]
+fn invalid_match_double(x: u32) -> u32 {
+    match x {
+        0 => 0,
+        1 => 2,
+        _ => x + 2,
+    }
+}
+
+// Should be valid
+#[condition(pre="true", post="(return: i8 >= -1i8) && (return: i8 <= 1i8)")]
+fn valid_match_signum(x: i8) -> i8 {
+    match x {
+        0 => 0,
+        -128...-1 => -1,
+        _ => 1,
+    }
+}
+
+// Should be invalid: 3 is not listed, so it takes the otherwise branch
+#[condition(pre="(x: i32 >= 1i32) && (x: i32 <= 3i32)", post="return: bool")]
+fn invalid_match_listed(x: i32) -> bool {
+    match x {
+        1 | 2 => true,
+        _ => false,
+    }
+}
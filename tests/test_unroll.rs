@@ -0,0 +1,53 @@
+// The Rust-Proof Project is copyright 2016, Sami Sahli,
+// Michael Salter, Matthew Slocum, Vincent Schuster,
+// Bradley Rasmussen, Drew Gohman, and Matthew O'Brien.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+#![feature(plugin, custom_attribute)]
+#![plugin(rustproof)]
+#![allow(dead_code)]
+#![allow(unused_attributes)]
+fn main() { }
+
+// Should be valid
+#[condition(pre="n: u32 <= 3u32", post="return: u32 == n: u32 * 2u32")]
+#[unroll="4"]
+fn valid_unrolled_double(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        sum += 2;
+        i += 1;
+    }
+    sum
+}
+
+// Should be invalid: the postcondition fails within the bound
+#[condition(pre="n: u32 <= 3u32", post="return: u32 == n: u32 * 3u32")]
+#[unroll="4"]
+fn invalid_unrolled_triple(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        sum += 2;
+        i += 1;
+    }
+    sum
+}
+
+// Should be invalid: the loop may run for more than 4 iterations
+#[condition(pre="n: u32 <= 10u32", post="return: u32 == n: u32 * 2u32")]
+#[unroll="4"]
+fn invalid_unrolled_double_bound(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        sum += 2;
+        i += 1;
+    }
+    sum
+}